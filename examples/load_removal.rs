@@ -0,0 +1,36 @@
+// cargo run --example load_removal --target $(rustc -vV | sed -n 's|host: ||p') -- path/to/trace.log
+
+extern crate asr;
+extern crate silksong_autosplit_wasm;
+extern crate std;
+
+#[cfg(not(target_os = "unknown"))]
+use silksong_autosplit_wasm::replay::{simulate_load_removal, ticks_to_string, Trace};
+#[cfg(not(target_os = "unknown"))]
+use std::{env, fs, io, process};
+
+fn main() -> std::io::Result<()> {
+    #[cfg(not(target_os = "unknown"))]
+    {
+        let Some(path) = env::args().nth(1) else {
+            eprintln!("usage: load_removal <trace file>");
+            process::exit(2);
+        };
+        let log = fs::read_to_string(path)?;
+        let trace = Trace::parse(&log).map_err(io::Error::other)?;
+
+        let report = simulate_load_removal(&trace);
+        for interval in report.paused.iter() {
+            println!("{}", interval);
+        }
+        println!();
+        println!("real time: {}", ticks_to_string(report.real_ticks));
+        println!("game time: {}", ticks_to_string(report.game_ticks));
+        println!(
+            "removed:   {}",
+            ticks_to_string(report.real_ticks - report.game_ticks)
+        );
+    }
+
+    Ok(())
+}
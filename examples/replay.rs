@@ -0,0 +1,29 @@
+// cargo run --example replay --target $(rustc -vV | sed -n 's|host: ||p') -- path/to/trace.log
+
+extern crate asr;
+extern crate silksong_autosplit_wasm;
+extern crate std;
+
+#[cfg(not(target_os = "unknown"))]
+use silksong_autosplit_wasm::replay::{replay, Trace, TICKS_PER_SECOND};
+#[cfg(not(target_os = "unknown"))]
+use std::{env, fs, io, process};
+
+fn main() -> std::io::Result<()> {
+    #[cfg(not(target_os = "unknown"))]
+    {
+        let Some(path) = env::args().nth(1) else {
+            eprintln!("usage: replay <trace file>");
+            process::exit(2);
+        };
+        let log = fs::read_to_string(path)?;
+        let trace = Trace::parse(&log).map_err(io::Error::other)?;
+
+        for event in replay(&trace) {
+            let seconds = event.tick as f64 / TICKS_PER_SECOND as f64;
+            println!("{} ({:.3}s)", event, seconds);
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,494 @@
+//! Counts that are kept per segment of a run, like hits and deaths,
+//! along with a comparison from earlier runs to show how the current run is doing.
+
+use alloc::{collections::BTreeMap, format, string::String, vec, vec::Vec};
+use core::cmp;
+
+use asr::settings::Gui;
+use ugly_widget::{radio_button::RadioButtonOptions, store::StoreWidget};
+
+use crate::{insert_option_str, runtime, DASH};
+
+/// Which events the hit counter counts, and how.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct HitOptions {
+    /// Getting knocked back by damage
+    pub recoil: bool,
+    /// Hazard deaths, like falling onto spikes
+    pub hazard: bool,
+    /// Health reaching 0
+    pub health_0: bool,
+    /// Knockbacks that take silk, even when `recoil` is off
+    pub silk_damage: bool,
+    /// Counting a hit that takes more than 1 mask once for each mask
+    pub double_damage: bool,
+    /// Counting the masks of health lost instead of the events
+    pub damage: bool,
+}
+
+impl Default for HitOptions {
+    fn default() -> Self {
+        HitOptions {
+            recoil: true,
+            hazard: true,
+            health_0: true,
+            silk_damage: false,
+            double_damage: false,
+            damage: false,
+        }
+    }
+}
+
+/// Which comparison from earlier runs the `comparison` and `delta` variables compare against.
+#[derive(Clone, Copy, Debug, Default, Eq, Gui, PartialEq, RadioButtonOptions)]
+pub enum CounterComparison {
+    /// Personal Best
+    ///
+    /// The run that ended with the fewest in total
+    #[default]
+    PersonalBest,
+    /// Sum of Best
+    ///
+    /// The best segments added up
+    SumOfBest,
+    /// Last Attempt
+    ///
+    /// The last run that got past the first split
+    LastAttempt,
+    /// Average
+    ///
+    /// The average of recent runs that reached each split
+    Average,
+}
+
+impl StoreWidget for CounterComparison {
+    fn insert_into(&self, settings_map: &asr::settings::Map, key: &str) -> bool {
+        insert_option_str(self, settings_map, key)
+    }
+}
+
+/// How many of the most recent runs the [CounterComparison::Average] mostly comes from.
+/// Past that, older runs fade out instead of counting equally.
+pub const AVERAGE_RUNS: i64 = 10;
+
+/// The settings keys that a [SegmentCounter] saves its comparisons under.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct CounterKeys {
+    /// Kept under the name from before there were other comparisons,
+    /// so that comparisons saved then carry over as the personal best
+    pub pb: &'static str,
+    pub best_segments: &'static str,
+    pub last_attempt: &'static str,
+    pub average_sums: &'static str,
+    pub average_counts: &'static str,
+}
+
+pub const HIT_KEYS: CounterKeys = CounterKeys {
+    pb: "comparison_hits",
+    best_segments: "best_segment_hits",
+    last_attempt: "last_attempt_hits",
+    average_sums: "average_sum_hits",
+    average_counts: "average_count_hits",
+};
+
+pub const DEATH_KEYS: CounterKeys = CounterKeys {
+    pb: "comparison_deaths",
+    best_segments: "best_segment_deaths",
+    last_attempt: "last_attempt_deaths",
+    average_sums: "average_sum_deaths",
+    average_counts: "average_count_deaths",
+};
+
+/// The names of the timer variables that a [SegmentCounter] sets.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct CounterVariables {
+    pub total: &'static str,
+    pub segment: &'static str,
+    pub pb: &'static str,
+    pub comparison: &'static str,
+    pub delta: &'static str,
+    pub best_segment: &'static str,
+    pub possible_savings: &'static str,
+}
+
+impl CounterVariables {
+    pub fn keys(&self) -> [&'static str; 7] {
+        [
+            self.total,
+            self.segment,
+            self.pb,
+            self.comparison,
+            self.delta,
+            self.best_segment,
+            self.possible_savings,
+        ]
+    }
+}
+
+pub const HIT_VARIABLES: CounterVariables = CounterVariables {
+    total: "hits",
+    segment: "segment hits",
+    pb: "pb hits",
+    comparison: "comparison hits",
+    delta: "delta hits",
+    best_segment: "best segment hits",
+    possible_savings: "possible hit savings",
+};
+
+pub const DEATH_VARIABLES: CounterVariables = CounterVariables {
+    total: "deaths",
+    segment: "segment deaths",
+    pb: "pb deaths",
+    comparison: "comparison deaths",
+    delta: "delta deaths",
+    best_segment: "best segment deaths",
+    possible_savings: "possible death savings",
+};
+
+pub struct SegmentCounter {
+    pub keys: CounterKeys,
+    pub variables: CounterVariables,
+    /// Whether to set the timer `variables`, which can be off while still counting
+    pub show: bool,
+    /// The count so far in the current run
+    pub total: i64,
+    /// The count in each segment of the current run
+    pub segment: Vec<i64>,
+    /// The total at the end of each segment up until the last split,
+    /// with skipped segments counted along with the next split
+    pub cumulative: Vec<i64>,
+    /// The total at the end of each segment in the personal best,
+    /// or None for a segment it has no total for
+    pub pb: Vec<Option<i64>>,
+    /// The fewest in each segment on its own, from earlier runs,
+    /// or None for a segment that hasn't been split from start to end yet
+    pub best_segments: Vec<Option<i64>>,
+    /// The total at the end of each segment in the last attempt
+    pub last_attempt: Vec<Option<i64>>,
+    /// The sum of the totals at the end of each segment from recent runs,
+    /// along with how many runs that is, up to [AVERAGE_RUNS]
+    pub average: Vec<Option<(i64, i64)>>,
+    /// Which comparison `comparison` comes from
+    pub kind: CounterComparison,
+    /// The total at the end of each segment to compare against,
+    /// or None for a segment without one
+    pub comparison: Vec<Option<i64>>,
+}
+
+impl SegmentCounter {
+    pub fn new(keys: CounterKeys, variables: CounterVariables) -> SegmentCounter {
+        SegmentCounter {
+            keys,
+            variables,
+            show: true,
+            total: 0,
+            segment: Vec::new(),
+            cumulative: Vec::new(),
+            pb: Vec::new(),
+            best_segments: Vec::new(),
+            last_attempt: Vec::new(),
+            average: Vec::new(),
+            kind: CounterComparison::default(),
+            comparison: Vec::new(),
+        }
+    }
+
+    /// Clears the counts of the current run.
+    pub fn reset(&mut self) {
+        self.total = 0;
+        self.segment.clear();
+        self.cumulative.clear();
+    }
+
+    /// Makes room for the count of segment `i` when the run starts there.
+    pub fn start(&mut self, i: usize) {
+        self.segment.resize(i + 1, 0);
+    }
+
+    /// Moves on to segment `new_i` after splitting.
+    pub fn split(&mut self, new_i: usize) {
+        self.segment.push(0);
+        self.cumulative.resize(new_i, self.total);
+    }
+
+    /// Moves on after skipping segment `old_i`,
+    /// so that its count is carried over to the next segment.
+    pub fn skip(&mut self, old_i: usize) {
+        self.segment.insert(old_i, 0);
+    }
+
+    /// Goes back to segment `new_i`,
+    /// where `unsplit` is the first segment from there back that was skipped or undone.
+    pub fn undo(&mut self, new_i: usize, unsplit: usize) {
+        self.segment[new_i] += self.segment.drain((new_i + 1)..).sum::<i64>();
+        if new_i < self.cumulative.len() {
+            self.cumulative.truncate(unsplit);
+        }
+    }
+
+    /// Finishes the run at split index `i`,
+    /// lining the comparison up with the splits that were reached.
+    pub fn end(&mut self, i: usize) {
+        self.cumulative.resize(i, self.total);
+        let cmp_len = self.comparison.len();
+        if i < cmp_len {
+            self.comparison.drain(0..(cmp_len - i));
+        }
+    }
+
+    /// Counts `n` more in segment `i`, and shows the new counts.
+    pub fn add(&mut self, i: usize, n: i64) {
+        self.total += n;
+        self.set_variable_int(self.variables.total, self.total);
+        self.segment.resize(i + 1, 0);
+        self.segment[i] += n;
+        self.set_variable_int(self.variables.segment, self.segment[i]);
+        if let Some(c) = self.comparison.get(i).copied().flatten() {
+            self.set_variable(self.variables.delta, &delta_string(self.total - c));
+        } else {
+            self.set_variable(self.variables.delta, DASH);
+        }
+    }
+
+    /// Saves this run into the comparisons, given whether it `ended` by reaching the last split,
+    /// and which segments were split rather than skipped.
+    pub fn merge_run(&mut self, ended: bool, segments_splitted: &[bool]) {
+        if ended
+            && self
+                .pb
+                .last()
+                .copied()
+                .flatten()
+                .is_none_or(|pb| self.total < pb)
+        {
+            self.pb = self.cumulative.iter().copied().map(Some).collect();
+        }
+        self.merge_best_segments(segments_splitted);
+        if !self.cumulative.is_empty() {
+            self.last_attempt = self.cumulative.iter().copied().map(Some).collect();
+        }
+        for (i, n) in self.cumulative.iter().enumerate() {
+            if self.average.len() <= i {
+                self.average.resize(i + 1, None);
+            }
+            let (sum, count) = self.average[i].get_or_insert((0, 0));
+            if AVERAGE_RUNS <= *count {
+                // fade out the older runs by taking away one average run
+                *sum -= *sum / *count;
+            } else {
+                *count += 1;
+            }
+            *sum += n;
+        }
+        self.update_comparison();
+    }
+
+    /// Saves the segments of this run into the best segments where they're better.
+    /// A segment only counts on its own if the segment before it was split too.
+    fn merge_best_segments(&mut self, segments_splitted: &[bool]) {
+        for (i, n) in self.segment.iter().enumerate() {
+            let split_on_its_own =
+                segments_splitted.get(i) == Some(&true) && (i == 0 || segments_splitted[i - 1]);
+            if !split_on_its_own {
+                continue;
+            }
+            if self.best_segments.len() <= i {
+                self.best_segments.resize(i + 1, None);
+            }
+            let best = &mut self.best_segments[i];
+            if best.is_none_or(|b| *n < b) {
+                *best = Some(*n);
+            }
+        }
+    }
+
+    /// Moves the comparisons from lining up with the `old` splits to lining up with the `new`
+    /// splits, given the [split_identities] of each.
+    /// A total follows the split it ends at, and a best segment is only kept
+    /// if the split before it is still the same, so that it still covers the same stretch.
+    pub fn migrate(&mut self, old: &[String], new: &[String]) {
+        self.pb = migrate_totals(&self.pb, old, new);
+        self.last_attempt = migrate_totals(&self.last_attempt, old, new);
+        self.average = migrate_totals(&self.average, old, new);
+        self.best_segments = new
+            .windows(2)
+            .map(|w| {
+                let j = old_segment(old, &w[1])?;
+                if old[j] != w[0] {
+                    return None;
+                }
+                self.best_segments.get(j).copied().flatten()
+            })
+            .collect();
+        trim_none(&mut self.best_segments);
+        self.update_comparison();
+    }
+
+    /// Picks which comparison the `comparison` and `delta` variables compare against.
+    pub fn select(&mut self, kind: CounterComparison) {
+        self.kind = kind;
+        self.update_comparison();
+    }
+
+    fn update_comparison(&mut self) {
+        self.comparison = match self.kind {
+            CounterComparison::PersonalBest => self.pb.clone(),
+            CounterComparison::SumOfBest => {
+                let mut sum = Some(0);
+                self.best_segments
+                    .iter()
+                    .map(|b| {
+                        sum = sum.zip(*b).map(|(s, b)| s + b);
+                        sum
+                    })
+                    .collect()
+            }
+            CounterComparison::LastAttempt => self.last_attempt.clone(),
+            CounterComparison::Average => self
+                .average
+                .iter()
+                .map(|a| a.map(|(sum, count)| (sum + count / 2) / count))
+                .collect(),
+        };
+        trim_none(&mut self.comparison);
+    }
+
+    /// The count in segment `i` of the comparison, from the difference in its totals.
+    fn comparison_segment(&self, i: usize) -> Option<i64> {
+        let end = self.comparison.get(i).copied().flatten()?;
+        let start = match i.checked_sub(1) {
+            Some(prev) => self.comparison.get(prev).copied().flatten()?,
+            None => 0,
+        };
+        Some(end - start)
+    }
+
+    fn set_variable(&self, key: &str, value: &str) {
+        if self.show {
+            runtime::timer::set_variable(key, value);
+        }
+    }
+
+    fn set_variable_int(&self, key: &str, value: i64) {
+        if self.show {
+            runtime::timer::set_variable_int(key, value);
+        }
+    }
+
+    /// Shows the counts for the start of a run.
+    pub fn show_reset(&self) {
+        self.set_variable_int(self.variables.total, self.total);
+        self.set_variable_int(self.variables.segment, 0);
+        self.set_variable(self.variables.comparison, DASH);
+        self.set_variable(self.variables.delta, DASH);
+    }
+
+    /// Shows dashes for the counts, when the counter is turned off.
+    pub fn show_off(&self) {
+        self.set_variable(self.variables.total, DASH);
+        self.set_variable(self.variables.segment, DASH);
+        self.set_variable(self.variables.comparison, DASH);
+        self.set_variable(self.variables.delta, DASH);
+    }
+
+    /// Shows the counts for segment `i`, after moving on to it.
+    pub fn show_segment(&self, i: usize) {
+        self.set_variable_int(self.variables.segment, self.segment[i]);
+        if let Some(c) = self.comparison.get(i).copied().flatten() {
+            self.set_variable_int(self.variables.comparison, c);
+            self.set_variable(self.variables.delta, &delta_string(self.total - c));
+        } else {
+            self.set_variable(self.variables.comparison, DASH);
+            self.set_variable(self.variables.delta, DASH);
+        }
+        let best = self.best_segments.get(i).copied().flatten();
+        if let Some(b) = best {
+            self.set_variable_int(self.variables.best_segment, b);
+        } else {
+            self.set_variable(self.variables.best_segment, DASH);
+        }
+        if let (Some(c), Some(b)) = (self.comparison_segment(i), best) {
+            self.set_variable_int(self.variables.possible_savings, cmp::max(0, c - b));
+        } else {
+            self.set_variable(self.variables.possible_savings, DASH);
+        }
+    }
+
+    /// Shows the total at the end of the comparison.
+    pub fn show_pb(&self) {
+        if let Some(Some(pb)) = self.pb.last() {
+            self.set_variable_int(self.variables.pb, *pb);
+        }
+    }
+}
+
+/// The index of the segment that ends at the split with identity `id` in `splits`.
+fn old_segment(splits: &[String], id: &String) -> Option<usize> {
+    // segment i ends at split i + 1
+    splits.iter().position(|s| s == id)?.checked_sub(1)
+}
+
+/// Moves totals at the end of each segment from lining up with the `old` splits
+/// to lining up with the `new` splits, following the split that each total ends at.
+fn migrate_totals<T: Copy>(totals: &[Option<T>], old: &[String], new: &[String]) -> Vec<Option<T>> {
+    let mut migrated: Vec<Option<T>> = new
+        .iter()
+        .skip(1)
+        .map(|id| totals.get(old_segment(old, id)?).copied().flatten())
+        .collect();
+    trim_none(&mut migrated);
+    migrated
+}
+
+fn trim_none<T>(values: &mut Vec<Option<T>>) {
+    while values.last().is_some_and(|v| v.is_none()) {
+        values.pop();
+    }
+}
+
+/// The identity of each split, from its key along with how many splits before it have the same
+/// key, such as `"Bell#0"` and `"Bell#1"`, so that saved comparisons can follow a split
+/// when other splits are inserted or removed around it.
+pub fn split_identities(keys: impl IntoIterator<Item = String>) -> Vec<String> {
+    let mut seen: BTreeMap<String, usize> = BTreeMap::new();
+    keys.into_iter()
+        .map(|key| {
+            let n = seen.entry(key.clone()).or_default();
+            let id = format!("{}#{}", key, n);
+            *n += 1;
+            id
+        })
+        .collect()
+}
+
+/// A short fingerprint of the [split_identities],
+/// to tell apart attempts that were run with different splits.
+pub fn fingerprint(identities: &[String]) -> String {
+    // FNV-1a
+    let mut h: u64 = 0xcbf29ce484222325;
+    for id in identities {
+        for b in id.bytes().chain([b'\n']) {
+            h ^= b as u64;
+            h = h.wrapping_mul(0x100000001b3);
+        }
+    }
+    format!("{:016x}", h)
+}
+
+/// Comparisons the way they're saved in the settings, with -1 for None.
+pub fn to_saved_list(values: &[Option<i64>]) -> Vec<i64> {
+    values.iter().map(|v| v.unwrap_or(-1)).collect()
+}
+
+/// Reads comparisons from the way they're saved in the settings.
+pub fn from_saved_list(list: &[i64]) -> Vec<Option<i64>> {
+    list.iter().map(|v| (0 <= *v).then_some(*v)).collect()
+}
+
+fn delta_string(i: i64) -> String {
+    if i == 0 {
+        "0".into()
+    } else {
+        format!("{:+}", i)
+    }
+}
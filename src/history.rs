@@ -0,0 +1,124 @@
+//! A bounded history of attempts, kept in the settings across sessions,
+//! to tell where runs tend to end.
+//!
+//! Each attempt is saved as one line of text, like
+//! ```text
+//! splits=2a553c61c1952e3a reached=2 ended=false hits=0,3 times=12.50,95.25,
+//! ```
+//! where `times` are the game's play time in seconds at each split index reached,
+//! starting with the start split, and empty where it couldn't be read.
+//! The play time is PlayerData's `playTime`, the game's own clock for the save file,
+//! not the timer's game time, since an auto splitter can't read that back from the timer.
+//! So the times don't follow the load removal settings.
+
+use alloc::{
+    collections::VecDeque,
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+
+/// How many of the most recent attempts the history keeps.
+pub const MAX_ATTEMPTS: usize = 50;
+
+/// How one attempt went.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Attempt {
+    /// The [crate::counter::fingerprint] of the splits the attempt was run with
+    pub splits: String,
+    /// The split index the attempt got to:
+    /// the segment it was reset in, or the number of segments if it ended
+    pub reached: u64,
+    /// Whether the attempt reached the last split
+    pub ended: bool,
+    /// The hits in each segment, or empty if the hit counter was off
+    pub hits: Vec<i64>,
+    /// PlayerData's `playTime` at each split index reached, starting with the start split
+    pub times: Vec<Option<f32>>,
+}
+
+impl Attempt {
+    pub fn parse(s: &str) -> Option<Attempt> {
+        let mut attempt = Attempt {
+            splits: String::new(),
+            reached: 0,
+            ended: false,
+            hits: Vec::new(),
+            times: Vec::new(),
+        };
+        for kv in s.split_whitespace() {
+            match kv.split_once('=')? {
+                ("splits", v) => attempt.splits = v.to_string(),
+                ("reached", v) => attempt.reached = v.parse().ok()?,
+                ("ended", v) => attempt.ended = v.parse().ok()?,
+                ("hits", v) => {
+                    attempt.hits = v
+                        .split(',')
+                        .filter(|h| !h.is_empty())
+                        .map(|h| h.parse().ok())
+                        .collect::<Option<_>>()?;
+                }
+                ("times", "") => attempt.times.clear(),
+                ("times", v) => {
+                    attempt.times = v
+                        .split(',')
+                        .map(|t| (!t.is_empty()).then(|| t.parse().ok()).flatten())
+                        .collect();
+                }
+                _ => return None,
+            }
+        }
+        Some(attempt)
+    }
+}
+
+impl core::fmt::Display for Attempt {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let hits: Vec<String> = self.hits.iter().map(|h| h.to_string()).collect();
+        let times: Vec<String> = self
+            .times
+            .iter()
+            .map(|t| t.map(|t| format!("{:.2}", t)).unwrap_or_default())
+            .collect();
+        write!(
+            f,
+            "splits={} reached={} ended={} hits={} times={}",
+            self.splits,
+            self.reached,
+            self.ended,
+            hits.join(","),
+            times.join(",")
+        )
+    }
+}
+
+/// The most recent attempts, oldest first, up to [MAX_ATTEMPTS].
+#[derive(Default)]
+pub struct AttemptHistory {
+    pub attempts: VecDeque<Attempt>,
+}
+
+impl AttemptHistory {
+    pub fn push(&mut self, attempt: Attempt) {
+        self.attempts.push_back(attempt);
+        while MAX_ATTEMPTS < self.attempts.len() {
+            self.attempts.pop_front();
+        }
+    }
+
+    /// How many attempts with the `splits` fingerprint got to segment `i`.
+    pub fn reaching(&self, splits: &str, i: u64) -> usize {
+        self.with_splits(splits).filter(|a| i <= a.reached).count()
+    }
+
+    /// How many attempts with the `splits` fingerprint were reset in segment `i`.
+    pub fn resets(&self, splits: &str, i: u64) -> usize {
+        self.with_splits(splits)
+            .filter(|a| a.reached == i && !a.ended)
+            .count()
+    }
+
+    fn with_splits<'a>(&'a self, splits: &'a str) -> impl Iterator<Item = &'a Attempt> {
+        self.attempts.iter().filter(move |a| a.splits == splits)
+    }
+}
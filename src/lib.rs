@@ -1,15 +1,32 @@
 #![no_std]
 extern crate alloc;
+#[cfg(not(target_os = "unknown"))]
+extern crate std;
 
 #[global_allocator]
 static ALLOC: dlmalloc::GlobalDlmalloc = dlmalloc::GlobalDlmalloc;
 
-mod silksong_memory;
+pub mod counter;
+pub mod history;
+pub mod load_removal;
+#[cfg(not(target_os = "unknown"))]
+pub mod replay;
+mod runtime;
+pub mod silksong_memory;
+pub mod snapshot;
 pub mod splits;
-mod store;
-mod timer;
-
-use alloc::{boxed::Box, format, string::String, vec::Vec};
+pub mod store;
+pub mod timer;
+pub mod trace;
+
+use alloc::{
+    boxed::Box,
+    collections::BTreeMap,
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
 #[cfg(feature = "split-index")]
 use asr::watcher::Pair;
 use asr::{
@@ -21,24 +38,32 @@ use asr::{
     timer::TimerState,
     Address64, Process,
 };
-use core::cmp;
 use ugly_widget::{
-    radio_button::{options_normalize, options_str},
+    radio_button::{options_normalize, options_str, RadioButtonOptions},
     store::{StoreGui, StoreWidget},
     ugly_list::UglyList,
 };
 
 use crate::{
+    counter::{
+        fingerprint, from_saved_list, split_identities, to_saved_list, CounterComparison,
+        CounterKeys, CounterVariables, HitOptions, SegmentCounter, DEATH_KEYS, DEATH_VARIABLES,
+        HIT_KEYS, HIT_VARIABLES,
+    },
+    history::{Attempt, AttemptHistory},
+    load_removal::{
+        pause_cause, LoadRemovalInputs, LoadRemovalMode, LoadRemovalOptions, PauseCause,
+    },
     silksong_memory::{
-        attach_silksong, get_game_state, get_health, Env, GameManagerPointers, Memory,
-        PlayerDataPointers, SceneStore, GAME_STATE_CUTSCENE, GAME_STATE_ENTERING_LEVEL,
-        GAME_STATE_EXITING_LEVEL, GAME_STATE_INACTIVE, GAME_STATE_LOADING, GAME_STATE_MAIN_MENU,
-        GAME_STATE_PLAYING, HERO_TRANSITION_STATE_WAITING_TO_ENTER_LEVEL, MENU_TITLE,
-        NON_MENU_GAME_STATES, OPENING_SCENES, QUIT_TO_MENU, UI_STATE_CUTSCENE, UI_STATE_PAUSED,
-        UI_STATE_PLAYING,
+        attach_silksong, get_game_state, get_health, get_play_time, Env, GameManagerPointers,
+        GameVersion, Memory, PlayerDataPointers, SceneStore, GAME_STATE_CUTSCENE,
+        GAME_STATE_ENTERING_LEVEL, GAME_STATE_INACTIVE, GAME_STATE_LOADING, GAME_STATE_MAIN_MENU,
+        GAME_STATE_PLAYING, MENU_TITLE, NON_MENU_GAME_STATES, OPENING_SCENES,
     },
+    splits::{fleas_rescued, SplitArgs},
     store::Store,
     timer::SplitterAction,
+    trace::{TraceHeader, TraceRecorder},
 };
 
 asr::async_main!(stable);
@@ -60,6 +85,93 @@ static CARGO_PKG_VERSION: &str = env!("CARGO_PKG_VERSION");
 /// The dash symbol to use for generic dashes in text.
 pub const DASH: &str = "—";
 
+/// The timer variables for live stats, set by [handle_stats].
+const STAT_VARIABLES: [&str; 9] = [
+    "masks",
+    "spool",
+    "rosaries",
+    "shell shards",
+    "crest",
+    "respawn scene",
+    "needle",
+    "tool pouch",
+    "crafting kit",
+];
+
+/// The timer variables from the attempt history, set by [handle_attempt_stats].
+const ATTEMPT_VARIABLES: [&str; 2] = ["attempts reaching this split", "reset rate on this split"];
+
+/// Which of the optional timer variables to set.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+struct TimerVariables {
+    /// `percent`
+    percent: bool,
+    /// `fleas`
+    fleas: bool,
+    /// `scene`
+    room: bool,
+    /// `room time`
+    room_time: bool,
+    /// [STAT_VARIABLES]
+    stats: bool,
+    /// [DEATH_VARIABLES], while the death counter is on
+    deaths: bool,
+    /// [ATTEMPT_VARIABLES], and recording the attempt history at all
+    attempts: bool,
+    /// `game version`
+    game_version: bool,
+}
+
+impl Default for TimerVariables {
+    fn default() -> Self {
+        TimerVariables {
+            percent: true,
+            fleas: false,
+            room: false,
+            room_time: false,
+            stats: false,
+            deaths: false,
+            attempts: false,
+            game_version: false,
+        }
+    }
+}
+
+impl TimerVariables {
+    /// The variables to register on start, given whether the hit counter is on.
+    fn keys(&self, hit_counter: bool) -> Vec<&'static str> {
+        let mut keys = Vec::new();
+        if hit_counter {
+            keys.extend(HIT_VARIABLES.keys());
+        }
+        if self.deaths {
+            keys.extend(DEATH_VARIABLES.keys());
+        }
+        if self.percent {
+            keys.push("percent");
+        }
+        if self.fleas {
+            keys.push("fleas");
+        }
+        if self.room {
+            keys.push("scene");
+        }
+        if self.room_time {
+            keys.push("room time");
+        }
+        if self.stats {
+            keys.extend(STAT_VARIABLES);
+        }
+        if self.attempts {
+            keys.extend(ATTEMPT_VARIABLES);
+        }
+        if self.game_version {
+            keys.push("game version");
+        }
+        keys
+    }
+}
+
 // --------------------------------------------------------
 
 struct AutoSplitterState {
@@ -72,20 +184,39 @@ struct AutoSplitterState {
     /// Some: Running, Paused, or Ended
     split_index: Option<u64>,
     segments_splitted: Vec<bool>,
+    /// The play time at each split index reached in the current run, starting with the start split
+    split_times: Vec<Option<f32>>,
+    /// The earlier attempts
+    history: AttemptHistory,
+    /// The split index that the attempt stats were last shown for,
+    /// or None if they need to be shown again
+    attempt_stats_index: Option<Option<u64>>,
     look_for_teleporting: bool,
     #[cfg(debug_assertions)]
     last_ui_state: i32,
     last_game_state: i32,
     #[cfg(debug_assertions)]
     last_hero_transition_state: i32,
-    hits: i64,
-    segment_hits: Vec<i64>,
-    cumulative_hits: Vec<i64>,
-    comparison_hits: Vec<i64>,
+    hits: SegmentCounter,
+    deaths: SegmentCounter,
+    /// The [split_identities] that the comparisons of `hits` and `deaths` line up with
+    comparison_splits: Vec<String>,
     last_recoil: bool,
     last_hazard: bool,
     last_health_0: bool,
+    /// The health on the last tick, to tell how much damage was taken
+    last_damage_health: Option<i32>,
+    /// The silk on the last tick, to tell whether a hit took any
+    last_silk: Option<i32>,
+    /// Whether the knockback going on now has already counted as a hit
+    recoil_counted: bool,
     mms_room_dupe: bool,
+    /// Why load removal paused game time on the last tick, if it did
+    pause_cause: Option<PauseCause>,
+    /// The live stats variables as they were last set, so they're only set again when changed
+    stats: BTreeMap<&'static str, String>,
+    /// The current room, and the play time when it was entered
+    room: Option<(String, f32)>,
     #[cfg(debug_assertions)]
     last_health: Option<i32>,
     #[cfg(debug_assertions)]
@@ -93,7 +224,7 @@ struct AutoSplitterState {
 }
 
 impl AutoSplitterState {
-    fn new() -> AutoSplitterState {
+    fn new(hits: SegmentCounter, deaths: SegmentCounter) -> AutoSplitterState {
         let mut store = Box::new(Store::new());
         let timer_state = store
             .get_timer_state_current()
@@ -101,26 +232,33 @@ impl AutoSplitterState {
         let split_index = store.get_split_index_current();
         let mut segments_splitted = Vec::new();
         segments_splitted.resize(split_index.unwrap_or_default() as usize, false);
-        let comparison_hits = Settings::get_comparison_hits().unwrap_or_default();
         AutoSplitterState {
             store,
             timer_state,
             split_index,
             segments_splitted,
+            split_times: Vec::new(),
+            history: AttemptHistory::default(),
+            attempt_stats_index: None,
             look_for_teleporting: false,
             #[cfg(debug_assertions)]
             last_ui_state: 0,
             last_game_state: GAME_STATE_INACTIVE,
             #[cfg(debug_assertions)]
             last_hero_transition_state: 0,
-            hits: 0,
-            segment_hits: Vec::new(),
-            cumulative_hits: Vec::new(),
-            comparison_hits,
+            hits,
+            deaths,
+            comparison_splits: Vec::new(),
             last_recoil: false,
             last_hazard: false,
             last_health_0: false,
+            last_damage_health: None,
+            last_silk: None,
+            recoil_counted: false,
             mms_room_dupe: false,
+            pause_cause: None,
+            stats: BTreeMap::new(),
+            room: None,
             #[cfg(debug_assertions)]
             last_health: None,
             #[cfg(debug_assertions)]
@@ -128,7 +266,7 @@ impl AutoSplitterState {
         }
     }
 
-    fn update(&mut self, settings: &Settings, env: Option<&Env>) {
+    fn update(&mut self, settings: &dyn SplitterSettings, env: Option<&Env>) {
         self.store.update_all(env);
         let Some(state_pair) = self.store.get_timer_state_pair() else {
             return;
@@ -148,14 +286,14 @@ impl AutoSplitterState {
                     || self.timer_state == TimerState::Ended =>
             {
                 // Reset
-                if settings.get_hit_counter() {
-                    Settings::update_comparison_hits(
-                        &mut self.comparison_hits,
-                        &self.cumulative_hits,
-                    );
-                    if self.timer_state == TimerState::Ended {
-                        if let Some(pb_hits) = self.comparison_hits.last() {
-                            asr::timer::set_variable_int("pb hits", *pb_hits);
+                let ended = self.timer_state == TimerState::Ended;
+                self.record_attempt(settings, ended);
+                let segments_splitted = core::mem::take(&mut self.segments_splitted);
+                for (c, on) in self.counters_mut(settings) {
+                    if on {
+                        save_comparisons(settings, c, ended, &segments_splitted);
+                        if ended {
+                            c.show_pb();
                         }
                     }
                 }
@@ -164,21 +302,22 @@ impl AutoSplitterState {
                     self.split_index = None;
                 }
                 self.segments_splitted.clear();
-                self.hits = 0;
-                self.segment_hits.clear();
-                self.cumulative_hits.clear();
-                if settings.get_hit_counter() {
-                    asr::timer::set_variable_int("hits", self.hits);
-                    asr::timer::set_variable_int("segment hits", 0);
-                } else {
-                    asr::timer::set_variable("hits", DASH);
-                    asr::timer::set_variable("segment hits", DASH);
+                for (c, on) in self.counters_mut(settings) {
+                    c.reset();
+                    if on {
+                        c.show_reset();
+                    }
+                }
+                // The hits variables have always shown dashes while the hit counter is off
+                if !settings.get_hit_counter() {
+                    self.hits.show_off();
+                }
+                if settings.get_timer_variables().percent {
+                    runtime::timer::set_variable("percent", DASH);
                 }
-                asr::timer::set_variable("comparison hits", DASH);
-                asr::timer::set_variable("delta hits", DASH);
-                asr::timer::set_variable("percent", DASH);
                 self.look_for_teleporting = false;
                 self.last_game_state = GAME_STATE_INACTIVE;
+                self.last_damage_health = None;
                 #[cfg(debug_assertions)]
                 {
                     self.last_paused = false;
@@ -191,20 +330,18 @@ impl AutoSplitterState {
                     .unwrap_or_default();
                 self.split_index = Some(new_index);
                 let new_i = new_index as usize;
-                self.segment_hits.resize(new_i + 1, 0);
-                if settings.get_hit_counter() {
-                    asr::timer::set_variable_int("segment hits", self.segment_hits[new_i]);
-                    if let Some(c) = self.comparison_hits.get(new_i) {
-                        asr::timer::set_variable_int("comparison hits", *c);
-                        asr::timer::set_variable("delta hits", &delta_string(self.hits - c));
-                    } else {
-                        asr::timer::set_variable("comparison hits", DASH);
-                        asr::timer::set_variable("delta hits", DASH);
+                self.split_times = vec![None; new_i];
+                self.split_times.push(get_play_time(env));
+                for (c, on) in self.counters_mut(settings) {
+                    c.start(new_i);
+                    if on {
+                        c.show_segment(new_i);
                     }
                 }
+                self.last_damage_health = None;
                 // InitializeGameTime
-                asr::timer::pause_game_time();
-                asr::timer::resume_game_time();
+                runtime::timer::pause_game_time();
+                runtime::timer::resume_game_time();
             }
             TimerState::Paused if self.timer_state == TimerState::Running => {
                 // Pause
@@ -233,13 +370,10 @@ impl AutoSplitterState {
                         // split_index = n
                     }
                 }
-                if settings.get_hit_counter() {
-                    if let Some(index) = self.split_index {
-                        let i = index as usize;
-                        self.cumulative_hits.resize(i, self.hits);
-                        let cmp_len = self.comparison_hits.len();
-                        if i < cmp_len {
-                            self.comparison_hits.drain(0..(cmp_len - i));
+                if let Some(index) = self.split_index {
+                    for (c, on) in self.counters_mut(settings) {
+                        if on {
+                            c.end(index as usize);
                         }
                     }
                 }
@@ -254,45 +388,44 @@ impl AutoSplitterState {
                     let new_i = new_index as usize;
                     if new_index < old_index {
                         // Undo
-                        self.segment_hits[new_i] +=
-                            self.segment_hits.drain((new_i + 1)..).sum::<i64>();
-                        if new_i < self.cumulative_hits.len() {
-                            let mut i = new_i;
-                            // go back through skipped splits
-                            while 1 <= i && !self.segments_splitted[i - 1] {
-                                i -= 1;
-                            }
-                            // segment [i - 1] was not skipped, but segment [i] was skipped or undone,
-                            // so remove cumulative_hits from there on
-                            self.cumulative_hits.truncate(i);
+                        let mut i = new_i;
+                        // go back through skipped splits
+                        while 1 <= i && !self.segments_splitted[i - 1] {
+                            i -= 1;
+                        }
+                        // segment [i - 1] was not skipped, but segment [i] was skipped or undone,
+                        // so remove cumulative counts from there on
+                        for (c, _) in self.counters_mut(settings) {
+                            c.undo(new_i, i);
                         }
                         self.segments_splitted.truncate(new_i);
+                        self.split_times.truncate(new_i + 1);
                     } else if new_index > old_index {
                         for old_idx in old_index..new_index {
                             let o_i = old_idx as usize;
                             let n_i = o_i + 1;
                             let splitted =
-                                asr::timer::segment_splitted(old_idx).unwrap_or_default();
+                                runtime::timer::segment_splitted(old_idx).unwrap_or_default();
                             self.segments_splitted.push(splitted);
-                            if splitted {
-                                // Split
-                                self.segment_hits.push(0);
-                                self.cumulative_hits.resize(n_i, self.hits);
-                            } else {
-                                // Skip
-                                self.segment_hits.insert(o_i, 0);
+                            self.split_times
+                                .push(get_play_time(env).filter(|_| splitted));
+                            for (c, _) in self.counters_mut(settings) {
+                                if splitted {
+                                    // Split
+                                    c.split(n_i);
+                                } else {
+                                    // Skip
+                                    c.skip(o_i);
+                                }
                             }
                         }
                     }
 
-                    if settings.get_hit_counter() && new_index != old_index {
-                        asr::timer::set_variable_int("segment hits", self.segment_hits[new_i]);
-                        if let Some(c) = self.comparison_hits.get(new_i) {
-                            asr::timer::set_variable_int("comparison hits", *c);
-                            asr::timer::set_variable("delta hits", &delta_string(self.hits - c));
-                        } else {
-                            asr::timer::set_variable("comparison hits", DASH);
-                            asr::timer::set_variable("delta hits", DASH);
+                    if new_index != old_index {
+                        for (c, on) in self.counters_mut(settings) {
+                            if on {
+                                c.show_segment(new_i);
+                            }
                         }
                     }
                 }
@@ -305,6 +438,83 @@ impl AutoSplitterState {
             self.split_index = p.current;
         }
     }
+
+    /// The hit and death counters, each with whether it's turned on in the `settings`.
+    fn counters_mut(
+        &mut self,
+        settings: &dyn SplitterSettings,
+    ) -> [(&mut SegmentCounter, bool); 2] {
+        self.deaths.show = settings.get_timer_variables().deaths;
+        [
+            (&mut self.hits, settings.get_hit_counter()),
+            (&mut self.deaths, settings.get_death_counter()),
+        ]
+    }
+
+    /// Adds the attempt that just finished to the history, and persists it,
+    /// given whether it `ended` by reaching the last split.
+    fn record_attempt(&mut self, settings: &dyn SplitterSettings, ended: bool) {
+        if !settings.get_timer_variables().attempts {
+            return;
+        }
+        let reached = self.split_index.unwrap_or_default();
+        let segments = if ended { reached } else { reached + 1 };
+        let hits = if settings.get_hit_counter() {
+            self.hits
+                .segment
+                .iter()
+                .copied()
+                .take(segments as usize)
+                .collect()
+        } else {
+            Vec::new()
+        };
+        self.history.push(Attempt {
+            splits: fingerprint(&self.comparison_splits),
+            reached,
+            ended,
+            hits,
+            times: core::mem::take(&mut self.split_times),
+        });
+        settings.set_attempt_history(&self.history);
+        self.attempt_stats_index = None;
+    }
+
+    /// Moves the comparisons over to the current splits after the splits have changed,
+    /// and persists them. Waits until between runs, so a run in progress keeps its comparisons.
+    fn align_comparisons(&mut self, settings: &dyn SplitterSettings) {
+        if !is_timer_state_between_runs(self.timer_state) {
+            return;
+        }
+        let splits = current_split_identities(settings);
+        if splits == self.comparison_splits {
+            return;
+        }
+        for c in [&mut self.hits, &mut self.deaths] {
+            c.migrate(&self.comparison_splits, &splits);
+            persist_comparisons(settings, c);
+        }
+        settings.set_comparison_splits(&splits);
+        self.comparison_splits = splits;
+        // the attempt history stats are for the old splits
+        self.attempt_stats_index = None;
+    }
+
+    /// Picks the comparison from the `settings` for each counter, if it changed.
+    fn select_comparisons(&mut self, settings: &dyn SplitterSettings) {
+        let kind = settings.get_counter_comparison();
+        let i = self.split_index.unwrap_or_default() as usize;
+        let running = !is_timer_state_between_runs(self.timer_state);
+        for (c, on) in self.counters_mut(settings) {
+            if c.kind == kind {
+                continue;
+            }
+            c.select(kind);
+            if on && running {
+                c.show_segment(i);
+            }
+        }
+    }
 }
 
 // --------------------------------------------------------
@@ -326,72 +536,395 @@ impl Widget for PkgVersion {
     }
 }
 
+/// The list of splits, along with the text settings in each split's row.
+struct SplitList {
+    list: UglyList<splits::Split>,
+    args: Vec<SplitArgs>,
+}
+
+impl SplitList {
+    fn keys(&self) -> Vec<String> {
+        self.list.get_list().into_iter().map(options_str).collect()
+    }
+
+    fn update_args(&mut self, settings_map: &asr::settings::Map, key: &str, old: &[String]) {
+        let new = self.keys();
+        // when rows are inserted, removed, or moved, the text settings are still
+        // where the old rows were, unless they were edited along with the list
+        if new != old && read_split_args(settings_map, key, old.len()) == self.args {
+            self.args = SplitArgs::migrate(&self.args, old, &new);
+        } else {
+            self.args = read_split_args(settings_map, key, new.len());
+        }
+    }
+}
+
+/// Reads the text settings of the first `len` rows of the splits list under `key`.
+fn read_split_args(settings_map: &asr::settings::Map, key: &str, len: usize) -> Vec<SplitArgs> {
+    (0..len)
+        .map(|i| {
+            let mut args = SplitArgs::default();
+            for name in SplitArgs::NAMES {
+                if let Some(v) = settings_map
+                    .get(&format!("{}_{}_{}", key, i, name))
+                    .and_then(|v| v.get_string())
+                {
+                    args.set(name, v);
+                }
+            }
+            args
+        })
+        .collect()
+}
+
+impl Widget for SplitList {
+    type Args = <UglyList<splits::Split> as Widget>::Args;
+
+    fn register(key: &str, description: &str, args: Self::Args) -> Self {
+        let mut s = SplitList {
+            list: UglyList::register(key, description, args),
+            args: Vec::new(),
+        };
+        let old = s.keys();
+        s.args = read_split_args(&asr::settings::Map::load(), key, old.len());
+        s
+    }
+
+    fn update_from(&mut self, settings_map: &asr::settings::Map, key: &str, args: Self::Args) {
+        let old = self.keys();
+        self.list.update_from(settings_map, key, args);
+        self.update_args(settings_map, key, &old);
+    }
+}
+
+impl StoreWidget for SplitList {
+    fn insert_into(&self, settings_map: &asr::settings::Map, key: &str) -> bool {
+        let mut changed = self.list.insert_into(settings_map, key);
+        // the text settings of each row, followed by clearing rows past the end of the list
+        let empty = SplitArgs::default();
+        for i in 0.. {
+            let args = self.args.get(i);
+            let mut any = false;
+            for name in SplitArgs::NAMES {
+                let k = format!("{}_{}_{}", key, i, name);
+                let old_s = settings_map.get(&k).and_then(|v| v.get_string());
+                any |= old_s.as_ref().is_some_and(|s| !s.is_empty());
+                let new_s = args.unwrap_or(&empty).get(name).unwrap_or_default();
+                if old_s.as_deref().unwrap_or_default() != new_s {
+                    settings_map.insert(&k, new_s);
+                    changed = true;
+                }
+            }
+            if args.is_none() && !any {
+                break;
+            }
+        }
+        changed
+    }
+}
+
+/// The settings that the per-tick handlers depend on,
+/// so that they can run from a replay as well as from the [Settings] Gui.
+trait SplitterSettings {
+    fn get_hit_counter(&self) -> bool;
+    fn get_death_counter(&self) -> bool;
+    fn get_hit_options(&self) -> HitOptions;
+    fn get_counter_comparison(&self) -> CounterComparison;
+    fn get_splits_len(&self) -> usize;
+    fn get_split(&self, i: u64) -> Option<splits::Split>;
+    fn get_split_args(&self, i: u64) -> Option<&SplitArgs>;
+    fn get_load_removal_options(&self) -> LoadRemovalOptions;
+    fn get_timer_variables(&self) -> TimerVariables;
+    /// Persists a comparison, such as the comparison hits, for future runs.
+    fn set_comparison(&self, key: &str, comparison: &[i64]);
+    /// Persists the [split_identities] that the comparisons line up with.
+    fn set_comparison_splits(&self, splits: &[String]);
+    /// Persists the attempt history for future sessions.
+    fn set_attempt_history(&self, history: &AttemptHistory);
+}
+
+fn current_split_identities(settings: &dyn SplitterSettings) -> Vec<String> {
+    split_identities(
+        (0..settings.get_splits_len() as u64)
+            .filter_map(|i| settings.get_split(i))
+            .map(|split| options_str(&split)),
+    )
+}
+
+/// Merges the run that just finished into the `counter`'s comparisons, and persists them,
+/// given whether it `ended` by reaching the last split.
+fn save_comparisons(
+    settings: &dyn SplitterSettings,
+    counter: &mut SegmentCounter,
+    ended: bool,
+    segments_splitted: &[bool],
+) {
+    counter.merge_run(ended, segments_splitted);
+    persist_comparisons(settings, counter);
+}
+
+fn persist_comparisons(settings: &dyn SplitterSettings, counter: &SegmentCounter) {
+    let keys = counter.keys;
+    settings.set_comparison(keys.pb, &to_saved_list(&counter.pb));
+    settings.set_comparison(keys.best_segments, &to_saved_list(&counter.best_segments));
+    settings.set_comparison(keys.last_attempt, &to_saved_list(&counter.last_attempt));
+    let (sums, counts): (Vec<_>, Vec<_>) = counter
+        .average
+        .iter()
+        .map(|a| a.map_or((-1, 0), |(sum, count)| (sum, count)))
+        .unzip();
+    settings.set_comparison(keys.average_sums, &sums);
+    settings.set_comparison(keys.average_counts, &counts);
+}
+
 #[derive(Gui)]
 struct Settings {
     _pkg_version: PkgVersion,
     /// Hit Counter
     #[default = true]
     hit_counter: bool,
+    /// Count Recoil (getting knocked back by damage)
+    #[default = true]
+    hit_recoil: bool,
+    /// Count Hazard Deaths
+    #[default = true]
+    hit_hazard: bool,
+    /// Count Health 0
+    #[default = true]
+    hit_health_0: bool,
+    /// Count Silk Damage (counts a hit that takes silk as it knocks back, even with Count Recoil off)
+    #[default = false]
+    hit_silk_damage: bool,
+    /// Count Double Damage (with Count Recoil, counts a hit that takes more than 1 mask once for each mask)
+    #[default = false]
+    hit_double_damage: bool,
+    /// Count Damage (counts the masks of health lost, instead of the events above)
+    #[default = false]
+    hit_damage: bool,
+    /// Death Counter (counts hazard deaths and health reaching 0, separately from hits)
+    #[default = false]
+    death_counter: bool,
+    /// Comparison (which earlier runs comparison hits and delta hits compare against)
+    hit_comparison: CounterComparison,
+    /// Load Removal
+    load_removal_mode: LoadRemovalMode,
+    /// Remove Hazard Respawns (pauses game time during the fade after hitting a hazard)
+    #[default = false]
+    remove_hazard_respawns: bool,
+    /// Remove Fast Travel (pauses game time while riding the bellway or ventrica, or teleporting)
+    #[default = false]
+    remove_fast_travel: bool,
+    /// Remove Tilemap Rebuilding (pauses game time while checking the tilemap on entering a scene)
+    #[default = false]
+    remove_tilemap: bool,
+    /// Record Trace (prints the game state every tick, for replaying offline)
+    #[default = false]
+    record_trace: bool,
+    /// Timer Variables (show them with a Text component's Custom Variable)
+    #[heading_level = 1]
+    _timer_variables: Title,
+    /// Completion Percent (percent)
+    #[default = true]
+    variable_percent: bool,
+    /// Fleas (fleas)
+    #[default = false]
+    variable_fleas: bool,
+    /// Current Room (scene)
+    #[default = false]
+    variable_room: bool,
+    /// Room Time (room time, in seconds of play time since entering the room)
+    #[default = false]
+    variable_room_time: bool,
+    /// Stats (masks, spool, rosaries, shell shards, crest, respawn scene, needle, tool pouch, crafting kit)
+    #[default = false]
+    variable_stats: bool,
+    /// Deaths (deaths, segment deaths, pb deaths, comparison deaths, delta deaths)
+    #[default = false]
+    variable_deaths: bool,
+    /// Attempt History (attempts reaching this split, reset rate on this split, from the last 50 attempts)
+    #[default = false]
+    variable_attempts: bool,
+    /// Game Version (game version, the build of the game that's running)
+    #[default = false]
+    variable_game_version: bool,
     /// Splits
     #[heading_level = 1]
-    splits: UglyList<splits::Split>,
+    splits: SplitList,
 }
 
 impl StoreGui for Settings {
     fn insert_into(&self, settings_map: &asr::settings::Map) -> bool {
         let a = self.hit_counter.insert_into(settings_map, "hit_counter");
-        let b = self.splits.insert_into(settings_map, "splits");
-        a || b
+        let h1 = self.hit_recoil.insert_into(settings_map, "hit_recoil");
+        let h2 = self.hit_hazard.insert_into(settings_map, "hit_hazard");
+        let h3 = self.hit_health_0.insert_into(settings_map, "hit_health_0");
+        let h7 = self
+            .hit_silk_damage
+            .insert_into(settings_map, "hit_silk_damage");
+        let h4 = self
+            .hit_double_damage
+            .insert_into(settings_map, "hit_double_damage");
+        let h5 = self.hit_damage.insert_into(settings_map, "hit_damage");
+        let h8 = self
+            .death_counter
+            .insert_into(settings_map, "death_counter");
+        let h6 = self
+            .hit_comparison
+            .insert_into(settings_map, "hit_comparison");
+        let m = self
+            .load_removal_mode
+            .insert_into(settings_map, "load_removal_mode");
+        let b = self
+            .remove_hazard_respawns
+            .insert_into(settings_map, "remove_hazard_respawns");
+        let c = self
+            .remove_fast_travel
+            .insert_into(settings_map, "remove_fast_travel");
+        let d = self
+            .remove_tilemap
+            .insert_into(settings_map, "remove_tilemap");
+        let e = self.record_trace.insert_into(settings_map, "record_trace");
+        let v1 = self
+            .variable_percent
+            .insert_into(settings_map, "variable_percent");
+        let v2 = self
+            .variable_fleas
+            .insert_into(settings_map, "variable_fleas");
+        let v3 = self
+            .variable_room
+            .insert_into(settings_map, "variable_room");
+        let v4 = self
+            .variable_room_time
+            .insert_into(settings_map, "variable_room_time");
+        let v5 = self
+            .variable_stats
+            .insert_into(settings_map, "variable_stats");
+        let v6 = self
+            .variable_deaths
+            .insert_into(settings_map, "variable_deaths");
+        let v7 = self
+            .variable_attempts
+            .insert_into(settings_map, "variable_attempts");
+        let v8 = self
+            .variable_game_version
+            .insert_into(settings_map, "variable_game_version");
+        let f = self.splits.insert_into(settings_map, "splits");
+        a || h1
+            || h2
+            || h3
+            || h4
+            || h5
+            || h6
+            || h7
+            || h8
+            || m
+            || b
+            || c
+            || d
+            || e
+            || v1
+            || v2
+            || v3
+            || v4
+            || v5
+            || v6
+            || v7
+            || v8
+            || f
     }
 }
 
-impl Settings {
-    pub fn get_hit_counter(&self) -> bool {
+impl SplitterSettings for Settings {
+    fn get_hit_counter(&self) -> bool {
         self.hit_counter
     }
-    pub fn get_splits_len(&self) -> usize {
-        self.splits.get_list().len()
+    fn get_death_counter(&self) -> bool {
+        self.death_counter
     }
-    pub fn get_splits(&self) -> Vec<splits::Split> {
-        self.splits.get_list().into_iter().cloned().collect()
+    fn get_hit_options(&self) -> HitOptions {
+        HitOptions {
+            recoil: self.hit_recoil,
+            hazard: self.hit_hazard,
+            health_0: self.hit_health_0,
+            silk_damage: self.hit_silk_damage,
+            double_damage: self.hit_double_damage,
+            damage: self.hit_damage,
+        }
     }
-    pub fn get_split(&self, i: u64) -> Option<splits::Split> {
-        self.splits.get_list().get(i as usize).cloned().cloned()
+    fn get_counter_comparison(&self) -> CounterComparison {
+        self.hit_comparison
     }
-
-    pub fn default_init_register() -> Settings {
-        default_splits_init();
-        let mut gui = Settings::register();
-        gui.loop_load_update_store();
-        gui
+    fn get_splits_len(&self) -> usize {
+        self.splits.list.get_list().len()
     }
-
-    pub fn get_comparison_hits() -> Option<Vec<i64>> {
-        let c = asr::settings::Map::load().get("comparison_hits")?;
-        Some(c.get_list()?.iter().filter_map(|i| i.get_i64()).collect())
+    fn get_split(&self, i: u64) -> Option<splits::Split> {
+        self.splits
+            .list
+            .get_list()
+            .get(i as usize)
+            .cloned()
+            .cloned()
     }
-
-    pub fn update_comparison_hits(comparison_hits: &mut Vec<i64>, cumulative_hits: &[i64]) {
-        // save cumulative_hits to comparison_hits
-        for i in 0..cumulative_hits.len() {
-            if i < comparison_hits.len() {
-                comparison_hits[i] = cmp::min(comparison_hits[i], cumulative_hits[i]);
-            } else {
-                comparison_hits.push(cumulative_hits[i]);
-            }
+    fn get_split_args(&self, i: u64) -> Option<&SplitArgs> {
+        self.splits.args.get(i as usize)
+    }
+    fn get_load_removal_options(&self) -> LoadRemovalOptions {
+        LoadRemovalOptions {
+            mode: self.load_removal_mode,
+            hazard_respawns: self.remove_hazard_respawns,
+            fast_travel: self.remove_fast_travel,
+            tilemap: self.remove_tilemap,
         }
-        Settings::set_comparison_hits(comparison_hits);
     }
-
-    fn set_comparison_hits(comparison_hits: &[i64]) {
+    fn get_timer_variables(&self) -> TimerVariables {
+        TimerVariables {
+            percent: self.variable_percent,
+            fleas: self.variable_fleas,
+            room: self.variable_room,
+            room_time: self.variable_room_time,
+            stats: self.variable_stats,
+            deaths: self.variable_deaths,
+            attempts: self.variable_attempts,
+            game_version: self.variable_game_version,
+        }
+    }
+    fn set_comparison(&self, key: &str, comparison: &[i64]) {
         let l = asr::settings::List::new();
-        for i in comparison_hits {
+        for i in comparison {
             l.push(*i);
         }
         loop {
             let old = asr::settings::Map::load();
             let new = old.clone();
-            new.insert("comparison_hits", &l);
+            new.insert(key, &l);
+            if new.store_if_unchanged(&old) {
+                return;
+            }
+        }
+    }
+    fn set_comparison_splits(&self, splits: &[String]) {
+        let l = asr::settings::List::new();
+        for id in splits {
+            l.push(id.as_str());
+        }
+        loop {
+            let old = asr::settings::Map::load();
+            let new = old.clone();
+            new.insert("comparison_splits", &l);
+            if new.store_if_unchanged(&old) {
+                return;
+            }
+        }
+    }
+    fn set_attempt_history(&self, history: &AttemptHistory) {
+        let l = asr::settings::List::new();
+        for attempt in &history.attempts {
+            l.push(attempt.to_string());
+        }
+        loop {
+            let old = asr::settings::Map::load();
+            let new = old.clone();
+            new.insert("attempt_history", &l);
             if new.store_if_unchanged(&old) {
                 return;
             }
@@ -399,12 +932,76 @@ impl Settings {
     }
 }
 
+impl Settings {
+    pub fn get_record_trace(&self) -> bool {
+        self.record_trace
+    }
+    pub fn get_splits(&self) -> Vec<splits::Split> {
+        self.splits.list.get_list().into_iter().cloned().collect()
+    }
+
+    pub fn default_init_register() -> Settings {
+        default_splits_init();
+        let mut gui = Settings::register();
+        gui.loop_load_update_store();
+        gui
+    }
+
+    /// A counter with the comparisons saved from earlier runs.
+    pub fn load_counter(&self, keys: CounterKeys, variables: CounterVariables) -> SegmentCounter {
+        let mut counter = SegmentCounter::new(keys, variables);
+        let load = |key| from_saved_list(&Settings::get_comparison(key).unwrap_or_default());
+        counter.pb = load(keys.pb);
+        counter.best_segments = load(keys.best_segments);
+        counter.last_attempt = load(keys.last_attempt);
+        let counts = Settings::get_comparison(keys.average_counts).unwrap_or_default();
+        counter.average = load(keys.average_sums)
+            .into_iter()
+            .zip(counts)
+            .map(|(sum, count)| sum.filter(|_| 0 < count).map(|sum| (sum, count)))
+            .collect();
+        counter.select(self.hit_comparison);
+        counter
+    }
+
+    /// The [split_identities] that the saved comparisons line up with,
+    /// or None if they were saved before split identities were.
+    pub fn get_comparison_splits() -> Option<Vec<String>> {
+        let splits = asr::settings::Map::load()
+            .get("comparison_splits")?
+            .get_list()?
+            .iter()
+            .filter_map(|id| id.get_string())
+            .collect();
+        Some(splits)
+    }
+
+    /// The attempt history saved from earlier sessions, skipping attempts that can't be read.
+    pub fn get_attempt_history() -> AttemptHistory {
+        let mut history = AttemptHistory::default();
+        let saved = asr::settings::Map::load()
+            .get("attempt_history")
+            .and_then(|l| l.get_list());
+        for attempt in saved.iter().flat_map(|l| l.iter()) {
+            if let Some(a) = attempt.get_string().as_deref().and_then(Attempt::parse) {
+                history.push(a);
+            }
+        }
+        history
+    }
+
+    pub fn get_comparison(key: &str) -> Option<Vec<i64>> {
+        let c = asr::settings::Map::load().get(key)?;
+        Some(c.get_list()?.iter().filter_map(|i| i.get_i64()).collect())
+    }
+}
+
 fn default_splits_init() -> asr::settings::Map {
     let settings1 = asr::settings::Map::load();
     let this_script = this_script_name();
     if let Some(script_name) = settings1.get("script_name") {
         if script_name.get_string().unwrap_or_default() != this_script {
-            asr::print_message(&format!(
+            runtime::print_message(&format!(
                 "error: settings for wrong script_name: {:?} vs {}",
                 script_name, this_script
             ));
@@ -418,9 +1015,9 @@ fn default_splits_init() -> asr::settings::Map {
         .get("splits")
         .is_some_and(|v| v.get_list().is_some_and(|l| !l.is_empty()))
     {
-        asr::print_message("Settings from asr::settings::Map::load");
+        runtime::print_message("Settings from asr::settings::Map::load");
         if asr_settings_normalize(&settings1).is_some() {
-            asr::print_message("Settings normalized");
+            runtime::print_message("Settings normalized");
             settings1.store();
         }
         return settings1;
@@ -434,7 +1031,7 @@ fn default_splits_init() -> asr::settings::Map {
         new.insert("script_name", this_script);
         new.insert("splits", &l);
         if new.store_if_unchanged(&old) {
-            asr::print_message("No settings found: default splits initialized");
+            runtime::print_message("No settings found: default splits initialized");
             return new;
         }
     }
@@ -471,33 +1068,43 @@ fn asr_settings_normalize(m: &asr::settings::Map) -> Option<()> {
 // --------------------------------------------------------
 
 async fn main() {
-    // register the variables on start
-    asr::timer::set_variable("hits", DASH);
-    asr::timer::set_variable("segment hits", DASH);
-    asr::timer::set_variable("pb hits", DASH);
-    asr::timer::set_variable("comparison hits", DASH);
-    asr::timer::set_variable("delta hits", DASH);
-    asr::timer::set_variable("percent", DASH);
-
-    asr::print_message("Hello, World!");
+    runtime::print_message("Hello, World!");
 
     let mut ticks_since_gui = 0;
     let mut settings = Settings::default_init_register();
-    asr::print_message(&format!("hit_counter: {:?}", settings.get_hit_counter()));
-    asr::print_message(&format!("splits: {:?}", settings.get_splits()));
-
-    let mut state = AutoSplitterState::new();
+    runtime::print_message(&format!("hit_counter: {:?}", settings.get_hit_counter()));
+    runtime::print_message(&format!("splits: {:?}", settings.get_splits()));
 
-    if settings.get_hit_counter() {
-        asr::timer::set_variable_int("hits", 0);
-        asr::timer::set_variable_int("segment hits", 0);
+    // register the enabled variables on start
+    for key in settings
+        .get_timer_variables()
+        .keys(settings.get_hit_counter())
+    {
+        runtime::timer::set_variable(key, DASH);
     }
 
-    if !state.comparison_hits.is_empty()
-        && (state.comparison_hits.len() + 1 == settings.get_splits_len())
-    {
-        if let Some(pb_hits) = state.comparison_hits.last() {
-            asr::timer::set_variable_int("pb hits", *pb_hits);
+    let mut state = AutoSplitterState::new(
+        settings.load_counter(HIT_KEYS, HIT_VARIABLES),
+        settings.load_counter(DEATH_KEYS, DEATH_VARIABLES),
+    );
+    if let Some(comparison_splits) = Settings::get_comparison_splits() {
+        state.comparison_splits = comparison_splits;
+        state.align_comparisons(&settings);
+    } else {
+        // comparisons from before split identities were saved are assumed to line up
+        state.comparison_splits = current_split_identities(&settings);
+        settings.set_comparison_splits(&state.comparison_splits);
+    }
+    state.history = Settings::get_attempt_history();
+
+    let splits_len = settings.get_splits_len();
+    for (c, on) in state.counters_mut(&settings) {
+        if on && c.show {
+            runtime::timer::set_variable_int(c.variables.total, 0);
+            runtime::timer::set_variable_int(c.variables.segment, 0);
+            if c.pb.len() + 1 == splits_len {
+                c.show_pb();
+            }
         }
     }
 
@@ -510,53 +1117,83 @@ async fn main() {
                 // TODO: Load some initial information from the process.
                 let mut scene_store = Box::new(SceneStore::new());
                 next_tick().await;
-                let mem = Memory::wait_attach(&process).await;
-                next_tick().await;
-                let gm = Box::new(GameManagerPointers::new());
-                let pd = Box::new(PlayerDataPointers::new());
-                let env = Env::new(&mem, &pd, &gm);
-                let _: bool = mem.deref(&gm.accepting_input).unwrap_or_default();
-                let _: Address64 = mem.deref(&gm.entry_gate_name).unwrap_or_default();
-                let _: i32 = mem.deref(&gm.game_state).unwrap_or_default();
-                let _: bool = mem.deref(&gm.hazard_death).unwrap_or_default();
-                let _: bool = mem.deref(&gm.hazard_respawning).unwrap_or_default();
-                let _: bool = mem.deref(&gm.hero_recoil_frozen).unwrap_or_default();
-                let _: i32 = mem.deref(&gm.hero_transition_state).unwrap_or_default();
-                let _: Address64 = mem.deref(&gm.scene_load).unwrap_or_default();
-                let _: bool = mem
-                    .deref(&gm.scene_load_activation_allowed)
-                    .unwrap_or_default();
-                let _: Address64 = mem.deref(&gm.next_scene_name).unwrap_or_default();
-                let _: Address64 = mem.deref(&gm.scene_name).unwrap_or_default();
-                let _: i32 = mem.deref(&gm.ui_state_vanilla).unwrap_or_default();
-                let _: bool = mem.deref(&pd.is_inventory_open).unwrap_or_default();
-                let _: i32 = mem.deref(&pd.health).unwrap_or_default();
-                state
-                    .store
-                    .get_i32_pair_bang("game_state", &get_game_state, Some(&env));
-                state
-                    .store
-                    .get_i32_pair_bang("health", &get_health, Some(&env));
-                next_tick().await;
-                asr::print_message("Initialized load removal pointers");
+                let mut mem = Memory::wait_attach(&process).await;
                 next_tick().await;
+                let mut recorder: Option<TraceRecorder> = None;
+                // wait_attach reads the build of the game unless a save file is loaded,
+                // otherwise once it's read on the main menu, start over with the pointers for it
                 loop {
-                    ticks_since_gui += 1;
-                    if TICKS_PER_GUI <= ticks_since_gui
-                        || (is_timer_state_between_runs(state.timer_state)
-                            && scene_store.pair().current == MENU_TITLE)
-                    {
-                        settings.load_update_store_if_unchanged();
-                        ticks_since_gui = 0;
-                    }
-                    state.update(&settings, Some(&env));
-
-                    // TODO: Do something on every tick.
-                    handle_splits(&settings, &mut state, &env, &mut scene_store).await;
-                    load_removal(&mut state, &env);
-                    handle_hits(&settings, &mut state, &env);
-                    handle_percent(&mem, &gm, &pd);
+                    let gm = Box::new(GameManagerPointers::for_version(mem.version.as_ref()));
+                    let pd = Box::new(PlayerDataPointers::for_version(mem.version.as_ref()));
+                    let env = Env::new(&mem, &pd, &gm);
+                    let _: bool = env.mem.deref(&gm.accepting_input).unwrap_or_default();
+                    let _: Address64 = env.mem.deref(&gm.entry_gate_name).unwrap_or_default();
+                    let _: i32 = env.mem.deref(&gm.game_state).unwrap_or_default();
+                    let _: bool = env.mem.deref(&gm.hazard_death).unwrap_or_default();
+                    let _: bool = env.mem.deref(&gm.hazard_respawning).unwrap_or_default();
+                    let _: bool = env.mem.deref(&gm.hero_recoil_frozen).unwrap_or_default();
+                    let _: i32 = env.mem.deref(&gm.hero_transition_state).unwrap_or_default();
+                    let _: Address64 = env.mem.deref(&gm.scene_load).unwrap_or_default();
+                    let _: bool = env
+                        .mem
+                        .deref(&gm.scene_load_activation_allowed)
+                        .unwrap_or_default();
+                    let _: Address64 = env.mem.deref(&gm.next_scene_name).unwrap_or_default();
+                    let _: Address64 = env.mem.deref(&gm.scene_name).unwrap_or_default();
+                    let _: i32 = env.mem.deref(&gm.ui_state_vanilla).unwrap_or_default();
+                    let _: bool = env.mem.deref(&pd.is_inventory_open).unwrap_or_default();
+                    let _: i32 = env.mem.deref(&pd.health).unwrap_or_default();
+                    state
+                        .store
+                        .get_i32_pair_bang("game_state", &get_game_state, Some(&env));
+                    state
+                        .store
+                        .get_i32_pair_bang("health", &get_health, Some(&env));
                     next_tick().await;
+                    runtime::print_message("Initialized load removal pointers");
+                    next_tick().await;
+                    let version = loop {
+                        ticks_since_gui += 1;
+                        if TICKS_PER_GUI <= ticks_since_gui
+                            || (is_timer_state_between_runs(state.timer_state)
+                                && scene_store.pair().current == MENU_TITLE)
+                        {
+                            settings.load_update_store_if_unchanged();
+                            state.align_comparisons(&settings);
+                            state.select_comparisons(&settings);
+                            ticks_since_gui = 0;
+                        }
+                        if settings.get_record_trace() {
+                            let r = recorder.get_or_insert_with(|| {
+                                let header = TraceHeader {
+                                    hit_counter: settings.get_hit_counter(),
+                                    hit_options: settings.get_hit_options(),
+                                    load_removal: settings.get_load_removal_options(),
+                                    splits: settings.get_splits(),
+                                    split_args: settings.splits.args.clone(),
+                                };
+                                runtime::print_message(&TraceRecorder::header(&header));
+                                TraceRecorder::new()
+                                    .with_split_args(&header.splits, &header.split_args)
+                            });
+                            if let Some(line) = r.record(&env) {
+                                runtime::print_message(&line);
+                            }
+                        } else {
+                            recorder = None;
+                        }
+
+                        handle_tick(&settings, &mut state, &env, &mut scene_store);
+                        handle_game_version(&settings, &mut state, mem.version.as_ref());
+                        if mem.version.is_none() {
+                            if let Some(version) = mem.read_build_version(&gm) {
+                                break version;
+                            }
+                        }
+                        next_tick().await;
+                    };
+                    runtime::print_message(&format!("game version = {}", version.0));
+                    mem.set_version(version);
                 }
             })
             .await;
@@ -567,6 +1204,8 @@ async fn wait_attach_silksong(gui: &mut Settings, state: &mut AutoSplitterState)
     retry(|| {
         gui.load_update_store_if_unchanged();
         state.update(gui, None);
+        state.align_comparisons(gui);
+        state.select_comparisons(gui);
         attach_silksong()
     })
     .await
@@ -574,8 +1213,24 @@ async fn wait_attach_silksong(gui: &mut Settings, state: &mut AutoSplitterState)
 
 // --------------------------------------------------------
 
-async fn handle_splits(
-    settings: &Settings,
+/// Everything that happens on every tick while attached to the game.
+fn handle_tick(
+    settings: &dyn SplitterSettings,
+    state: &mut AutoSplitterState,
+    env: &Env,
+    ss: &mut SceneStore,
+) {
+    state.update(settings, Some(env));
+    handle_splits(settings, state, env, ss);
+    load_removal(settings, state, env);
+    handle_hits_and_deaths(settings, state, env);
+    handle_percent(settings, env);
+    handle_stats(settings, state, env);
+    handle_attempt_stats(settings, state);
+}
+
+fn handle_splits(
+    settings: &dyn SplitterSettings,
     state: &mut AutoSplitterState,
     env: &Env<'_>,
     ss: &mut SceneStore,
@@ -588,31 +1243,25 @@ async fn handle_splits(
                 let Some(split) = settings.get_split(0) else {
                     break;
                 };
-                let a = splits::splits(&split, env, trans_now, ss, &mut state.store);
+                let args = settings.get_split_args(0);
+                let a = splits::splits(&split, args, env, trans_now, ss, &mut state.store);
                 match a {
                     SplitterAction::Split => {
                         // Start
-                        asr::timer::start();
+                        runtime::timer::start();
                         state.timer_state = TimerState::Running;
                         state.split_index = Some(0);
-                        state.segment_hits.resize(1, 0);
-                        if settings.get_hit_counter() {
-                            asr::timer::set_variable_int("segment hits", state.segment_hits[0]);
-                            // .first() = .get(0)
-                            if let Some(c) = state.comparison_hits.first() {
-                                asr::timer::set_variable_int("comparison hits", *c);
-                                asr::timer::set_variable(
-                                    "delta hits",
-                                    &delta_string(state.hits - c),
-                                );
-                            } else {
-                                asr::timer::set_variable("comparison hits", DASH);
-                                asr::timer::set_variable("delta hits", DASH);
+                        state.split_times = vec![get_play_time(Some(env))];
+                        for (c, on) in state.counters_mut(settings) {
+                            c.start(0);
+                            if on {
+                                c.show_segment(0);
                             }
                         }
+                        state.last_damage_health = None;
                         // InitializeGameTime
-                        asr::timer::pause_game_time();
-                        asr::timer::resume_game_time();
+                        runtime::timer::pause_game_time();
+                        runtime::timer::resume_game_time();
                         break;
                     }
                     _ => break,
@@ -620,38 +1269,40 @@ async fn handle_splits(
             }
             TimerState::Running | TimerState::Paused => {
                 // TODO: look up from settings
-                let Some(split) = settings.get_split(state.split_index.unwrap_or_default() + 1)
-                else {
+                let i = state.split_index.unwrap_or_default() + 1;
+                let Some(split) = settings.get_split(i) else {
                     break;
                 };
-                let a = splits::splits(&split, env, trans_now, ss, &mut state.store);
+                let args = settings.get_split_args(i);
+                let a = splits::splits(&split, args, env, trans_now, ss, &mut state.store);
                 match a {
                     SplitterAction::Reset => {
-                        if settings.get_hit_counter() {
-                            Settings::update_comparison_hits(
-                                &mut state.comparison_hits,
-                                &state.cumulative_hits,
-                            );
+                        state.record_attempt(settings, false);
+                        let segments_splitted = core::mem::take(&mut state.segments_splitted);
+                        for (c, on) in state.counters_mut(settings) {
+                            if on {
+                                save_comparisons(settings, c, false, &segments_splitted);
+                            }
                         }
-                        asr::timer::reset();
+                        runtime::timer::reset();
                         state.timer_state = TimerState::NotRunning;
                         state.split_index = None;
                         state.segments_splitted.clear();
-                        state.hits = 0;
-                        state.segment_hits.clear();
-                        state.cumulative_hits.clear();
-                        if settings.get_hit_counter() {
-                            asr::timer::set_variable_int("hits", state.hits);
-                            asr::timer::set_variable_int("segment hits", 0);
-                        } else {
-                            asr::timer::set_variable("hits", DASH);
-                            asr::timer::set_variable("segment hits", DASH);
+                        for (c, on) in state.counters_mut(settings) {
+                            c.reset();
+                            if on {
+                                c.show_reset();
+                            }
+                        }
+                        if !settings.get_hit_counter() {
+                            state.hits.show_off();
+                        }
+                        if settings.get_timer_variables().percent {
+                            runtime::timer::set_variable("percent", DASH);
                         }
-                        asr::timer::set_variable("comparison hits", DASH);
-                        asr::timer::set_variable("delta hits", DASH);
-                        asr::timer::set_variable("percent", DASH);
                         state.look_for_teleporting = false;
                         state.last_game_state = GAME_STATE_INACTIVE;
+                        state.last_damage_health = None;
                         #[cfg(debug_assertions)]
                         {
                             state.last_paused = false;
@@ -661,45 +1312,30 @@ async fn handle_splits(
                     SplitterAction::Skip => {
                         let old_index = state.split_index.unwrap_or_default();
                         let old_i = old_index as usize;
-                        asr::timer::skip_split();
+                        runtime::timer::skip_split();
                         let new_i = old_i + 1;
                         state.split_index = Some(old_index + 1);
                         state.segments_splitted.push(false);
-                        state.segment_hits.insert(old_i, 0);
-                        if settings.get_hit_counter() {
-                            asr::timer::set_variable_int("segment hits", state.segment_hits[new_i]);
-                            if let Some(c) = state.comparison_hits.get(new_i) {
-                                asr::timer::set_variable_int("comparison hits", *c);
-                                asr::timer::set_variable(
-                                    "delta hits",
-                                    &delta_string(state.hits - c),
-                                );
-                            } else {
-                                asr::timer::set_variable("comparison hits", DASH);
-                                asr::timer::set_variable("delta hits", DASH);
+                        state.split_times.push(None);
+                        for (c, on) in state.counters_mut(settings) {
+                            c.skip(old_i);
+                            if on {
+                                c.show_segment(new_i);
                             }
                         }
                         // no break, allow other actions after a skip or reset
                     }
                     SplitterAction::Split => {
                         let old_index = state.split_index.unwrap_or_default();
-                        asr::timer::split();
+                        runtime::timer::split();
                         let new_i = old_index as usize + 1;
                         state.split_index = Some(old_index + 1);
                         state.segments_splitted.push(true);
-                        state.segment_hits.push(0);
-                        state.cumulative_hits.resize(new_i, state.hits);
-                        if settings.get_hit_counter() {
-                            asr::timer::set_variable_int("segment hits", state.segment_hits[new_i]);
-                            if let Some(c) = state.comparison_hits.get(new_i) {
-                                asr::timer::set_variable_int("comparison hits", *c);
-                                asr::timer::set_variable(
-                                    "delta hits",
-                                    &delta_string(state.hits - c),
-                                );
-                            } else {
-                                asr::timer::set_variable("comparison hits", DASH);
-                                asr::timer::set_variable("delta hits", DASH);
+                        state.split_times.push(get_play_time(Some(env)));
+                        for (c, on) in state.counters_mut(settings) {
+                            c.split(new_i);
+                            if on {
+                                c.show_segment(new_i);
                             }
                         }
                         break;
@@ -716,21 +1352,11 @@ async fn handle_splits(
                             // new_i < number_of_segments
                             state.split_index = Some(old_index + 1);
                             state.segments_splitted.push(false);
-                            state.segment_hits.insert(old_i, 0);
-                            if settings.get_hit_counter() {
-                                asr::timer::set_variable_int(
-                                    "segment hits",
-                                    state.segment_hits[new_i],
-                                );
-                                if let Some(c) = state.comparison_hits.get(new_i) {
-                                    asr::timer::set_variable_int("comparison hits", *c);
-                                    asr::timer::set_variable(
-                                        "delta hits",
-                                        &delta_string(state.hits - c),
-                                    );
-                                } else {
-                                    asr::timer::set_variable("comparison hits", DASH);
-                                    asr::timer::set_variable("delta hits", DASH);
+                            state.split_times.push(None);
+                            for (c, on) in state.counters_mut(settings) {
+                                c.skip(old_i);
+                                if on {
+                                    c.show_segment(new_i);
                                 }
                             }
                         }
@@ -744,9 +1370,10 @@ async fn handle_splits(
     }
 }
 
-fn load_removal(state: &mut AutoSplitterState, e: &Env) {
+fn load_removal(settings: &dyn SplitterSettings, state: &mut AutoSplitterState, e: &Env) {
     // only remove loads if timer is running
-    if asr::timer::state() != TimerState::Running {
+    if runtime::timer::state() != TimerState::Running {
+        state.pause_cause = None;
         return;
     }
 
@@ -756,11 +1383,6 @@ fn load_removal(state: &mut AutoSplitterState, e: &Env) {
     let scene_name = mem.read_string(&gm.scene_name).unwrap_or_default();
     let next_scene = mem.read_string(&gm.next_scene_name).unwrap_or_default();
 
-    let loading_menu = (scene_name == QUIT_TO_MENU)
-        || (scene_name != MENU_TITLE && (next_scene.is_empty() || next_scene == MENU_TITLE));
-
-    // TODO: teleporting, look_for_teleporting
-
     let game_state: i32 = mem.deref(&gm.game_state).unwrap_or_default();
 
     if game_state == GAME_STATE_PLAYING && state.last_game_state == GAME_STATE_MAIN_MENU {
@@ -778,168 +1400,342 @@ fn load_removal(state: &mut AutoSplitterState, e: &Env) {
     {
         #[cfg(debug_assertions)]
         if !state.mms_room_dupe {
-            asr::print_message("mms_room_dupe: true");
+            runtime::print_message("mms_room_dupe: true");
         }
         state.mms_room_dupe = true;
     } else if game_state == GAME_STATE_PLAYING {
         #[cfg(debug_assertions)]
         if state.mms_room_dupe {
-            asr::print_message("mms_room_dupe: false");
+            runtime::print_message("mms_room_dupe: false");
         }
         state.mms_room_dupe = false;
     }
 
-    // TODO: hazard_respawning
-    let accepting_input: bool = mem.deref(&gm.accepting_input).unwrap_or_default();
-    let hero_transition_state: i32 = mem.deref(&gm.hero_transition_state).unwrap_or_default();
-    let scene_load_null: bool = mem
-        .deref(&gm.scene_load)
-        .is_ok_and(|a: Address64| a.is_null());
-    let scene_load_activation_allowed: bool = mem
-        .deref(&gm.scene_load_activation_allowed)
-        .unwrap_or_default();
-    let is_inventory_open: bool = mem.deref(&pd.is_inventory_open).unwrap_or_default();
-    // TODO: tile_map_dirty, uses_scene_transition_routine
-
-    let is_game_time_paused = (state.look_for_teleporting)
-        || ((game_state == GAME_STATE_PLAYING || game_state == GAME_STATE_ENTERING_LEVEL)
-            && ui_state != UI_STATE_PLAYING)
-        || (game_state != GAME_STATE_PLAYING
-            && game_state != GAME_STATE_CUTSCENE
-            && !accepting_input
-            && !state.mms_room_dupe)
-        || ((game_state == GAME_STATE_EXITING_LEVEL
-            && (scene_load_null || scene_load_activation_allowed)
-            && !is_inventory_open
-            && !state.mms_room_dupe)
-            || game_state == GAME_STATE_LOADING)
-        || (hero_transition_state == HERO_TRANSITION_STATE_WAITING_TO_ENTER_LEVEL
-            && !is_inventory_open)
-        || (ui_state != UI_STATE_PLAYING
-            && (loading_menu
-                || (ui_state != UI_STATE_PAUSED
-                    && ui_state != UI_STATE_CUTSCENE
-                    && (!next_scene.is_empty())))
-            && next_scene != scene_name);
+    let inputs = LoadRemovalInputs {
+        game_state,
+        ui_state,
+        accepting_input: mem.deref(&gm.accepting_input).unwrap_or_default(),
+        hero_transition_state: mem.deref(&gm.hero_transition_state).unwrap_or_default(),
+        scene_load_null: mem
+            .deref(&gm.scene_load)
+            .is_ok_and(|a: Address64| a.is_null()),
+        scene_load_activation_allowed: mem
+            .deref(&gm.scene_load_activation_allowed)
+            .unwrap_or_default(),
+        is_inventory_open: mem.deref(&pd.is_inventory_open).unwrap_or_default(),
+        scene_name,
+        next_scene,
+        mms_room_dupe: state.mms_room_dupe,
+        look_for_teleporting: state.look_for_teleporting,
+        hazard_respawning: mem.deref(&gm.hazard_respawning).unwrap_or_default(),
+        fast_travelling: mem.deref(&pd.travelling).unwrap_or_default()
+            || mem.deref(&pd.is_teleporting).unwrap_or_default(),
+        // Silksong has no tilemapDirty like Hollow Knight, but the hero's tilemap test covers
+        // the same stretch of setting up a scene
+        tilemap_rebuilding: mem.deref(&gm.hero_tilemap_test_active).unwrap_or_default(),
+    };
+
+    state.pause_cause = pause_cause(&inputs, &settings.get_load_removal_options());
+    let is_game_time_paused = state.pause_cause.is_some();
     if is_game_time_paused {
-        asr::timer::pause_game_time();
+        runtime::timer::pause_game_time();
     } else {
-        asr::timer::resume_game_time();
+        runtime::timer::resume_game_time();
     }
 
     #[cfg(debug_assertions)]
     {
         if ui_state != state.last_ui_state {
-            asr::print_message(&format!("ui_state: {}", ui_state));
+            runtime::print_message(&format!("ui_state: {}", ui_state));
         }
         state.last_ui_state = ui_state;
     }
 
     #[cfg(debug_assertions)]
     if game_state != state.last_game_state {
-        asr::print_message(&format!("game_state: {}", game_state));
+        runtime::print_message(&format!("game_state: {}", game_state));
     }
     state.last_game_state = game_state;
 
     #[cfg(debug_assertions)]
     {
-        if hero_transition_state != state.last_hero_transition_state {
-            asr::print_message(&format!("hero_transition_state: {}", hero_transition_state));
+        if inputs.hero_transition_state != state.last_hero_transition_state {
+            runtime::print_message(&format!(
+                "hero_transition_state: {}",
+                inputs.hero_transition_state
+            ));
         }
-        state.last_hero_transition_state = hero_transition_state;
+        state.last_hero_transition_state = inputs.hero_transition_state;
     }
 
     #[cfg(debug_assertions)]
     {
         if is_game_time_paused != state.last_paused {
-            asr::print_message(&format!("is_game_time_paused: {}", is_game_time_paused));
+            runtime::print_message(&format!("is_game_time_paused: {}", is_game_time_paused));
         }
         state.last_paused = is_game_time_paused;
     }
 }
 
-fn handle_hits(settings: &Settings, state: &mut AutoSplitterState, e: &Env) {
-    // only count hits if hit counter is true
-    if !settings.get_hit_counter() {
+fn handle_hits_and_deaths(settings: &dyn SplitterSettings, state: &mut AutoSplitterState, e: &Env) {
+    // only count hits if hit counter is true, and deaths if death counter is
+    let hit_counter = settings.get_hit_counter();
+    let death_counter = settings.get_death_counter();
+    state.deaths.show = settings.get_timer_variables().deaths;
+    if !hit_counter && !death_counter {
         return;
     }
+
+    let Env { mem, gm, pd } = e;
+    // keep the health and silk up to date while paused,
+    // so what changed in the meantime doesn't count once the timer is running again
+    let maybe_health: Option<i32> = mem.deref(&pd.health).ok();
+    let last_damage_health = core::mem::replace(&mut state.last_damage_health, maybe_health);
+    let maybe_silk: Option<i32> = mem.deref(&pd.silk).ok();
+    let last_silk = core::mem::replace(&mut state.last_silk, maybe_silk);
     // only count hits if timer is running
-    if asr::timer::state() != TimerState::Running {
+    if runtime::timer::state() != TimerState::Running {
         return;
     }
 
-    let Env { mem, gm, pd } = e;
+    let i = state.split_index.unwrap_or_default() as usize;
+    let hit_options = settings.get_hit_options();
+    // count events as hits, unless counting damage instead
+    let hit_events = hit_counter && !hit_options.damage;
 
     let recoil: bool = mem.deref(&gm.hero_recoil_frozen).unwrap_or_default();
-    if !state.last_recoil && recoil {
-        add_hit(state);
+    if !state.last_recoil && recoil && hit_events && hit_options.recoil {
+        state.hits.add(i, 1);
+        state.recoil_counted = true;
+        #[cfg(debug_assertions)]
+        runtime::print_message(&format!("hit: {}, from recoil", state.hits.total));
+    }
+    // silk can't be spent during a knockback, so silk lost then was taken by the hit
+    let silk_lost = matches!((last_silk, maybe_silk), (Some(old), Some(new)) if new < old);
+    if recoil && silk_lost && !state.recoil_counted && hit_events && hit_options.silk_damage {
+        state.hits.add(i, 1);
+        state.recoil_counted = true;
         #[cfg(debug_assertions)]
-        asr::print_message(&format!("hit: {}, from recoil", state.hits));
+        runtime::print_message(&format!("hit: {}, from silk damage", state.hits.total));
+    }
+    if !recoil {
+        state.recoil_counted = false;
     }
     state.last_recoil = recoil;
 
     let hazard: bool = mem.deref(&gm.hazard_death).unwrap_or_default();
     if !state.last_hazard && hazard {
-        add_hit(state);
-        #[cfg(debug_assertions)]
-        asr::print_message(&format!("hit: {}, from hazard", state.hits));
+        if hit_events && hit_options.hazard {
+            state.hits.add(i, 1);
+            #[cfg(debug_assertions)]
+            runtime::print_message(&format!("hit: {}, from hazard", state.hits.total));
+        }
+        if death_counter {
+            state.deaths.add(i, 1);
+            #[cfg(debug_assertions)]
+            runtime::print_message(&format!("death: {}, from hazard", state.deaths.total));
+        }
     }
     state.last_hazard = hazard;
 
-    let maybe_health: Option<i32> = mem.deref(&pd.health).ok();
     let game_state: i32 = mem.deref(&gm.game_state).unwrap_or_default();
     let health_0 = maybe_health == Some(0) && game_state == GAME_STATE_PLAYING;
     if !state.last_health_0 && health_0 {
-        add_hit(state);
-        #[cfg(debug_assertions)]
-        asr::print_message(&format!("hit: {}, from heath 0", state.hits));
+        if hit_events && hit_options.health_0 {
+            state.hits.add(i, 1);
+            #[cfg(debug_assertions)]
+            runtime::print_message(&format!("hit: {}, from heath 0", state.hits.total));
+        }
+        if death_counter {
+            state.deaths.add(i, 1);
+            #[cfg(debug_assertions)]
+            runtime::print_message(&format!("death: {}, from heath 0", state.deaths.total));
+        }
     }
     state.last_health_0 = health_0;
 
+    let damage = match (last_damage_health, maybe_health) {
+        (Some(old), Some(new)) if new < old && game_state == GAME_STATE_PLAYING => old - new,
+        _ => 0,
+    };
+    if hit_counter && hit_options.damage && 0 < damage {
+        state.hits.add(i, damage.into());
+        #[cfg(debug_assertions)]
+        runtime::print_message(&format!(
+            "hit: {}, from {} damage",
+            state.hits.total, damage
+        ));
+    } else if hit_events && hit_options.recoil && hit_options.double_damage && 1 < damage {
+        // the first mask counts as the recoil hit itself
+        state.hits.add(i, (damage - 1).into());
+        #[cfg(debug_assertions)]
+        runtime::print_message(&format!(
+            "hit: {}, from {} damage",
+            state.hits.total, damage
+        ));
+    }
+
     #[cfg(debug_assertions)]
     {
         if maybe_health != state.last_health {
-            asr::print_message(&format!("health: {:?}", maybe_health));
+            runtime::print_message(&format!("health: {:?}", maybe_health));
         }
         state.last_health = maybe_health;
     }
 }
 
-fn add_hit(state: &mut AutoSplitterState) {
-    state.hits += 1;
-    asr::timer::set_variable_int("hits", state.hits);
-    let i = state.split_index.unwrap_or_default() as usize;
-    state.segment_hits.resize(i + 1, 0);
-    state.segment_hits[i] += 1;
-    asr::timer::set_variable_int("segment hits", state.segment_hits[i]);
-    if let Some(c) = state.comparison_hits.get(i) {
-        asr::timer::set_variable("delta hits", &delta_string(state.hits - c));
-    } else {
-        asr::timer::set_variable("delta hits", DASH);
+fn handle_percent(settings: &dyn SplitterSettings, e: &Env) {
+    // only update percent if it's enabled, and timer is running or paused
+    if !settings.get_timer_variables().percent {
+        return;
     }
-}
-
-fn handle_percent(mem: &Memory, gm: &GameManagerPointers, pd: &PlayerDataPointers) {
-    // only update percent if timer is running or paused
-    if is_timer_state_between_runs(asr::timer::state()) {
+    if is_timer_state_between_runs(runtime::timer::state()) {
         return;
     }
 
+    let Env { mem, gm, pd } = e;
+
     // only update percent if game state is non-menu
     let game_state: i32 = mem.deref(&gm.game_state).unwrap_or_default();
     if !NON_MENU_GAME_STATES.contains(&game_state) {
         return;
     }
 
-    let Ok(percent) = mem.deref::<f32, _>(&pd.completion_percentage) else {
+    let Ok(percent) = mem.deref::<f32>(&pd.completion_percentage) else {
         return;
     };
 
-    asr::timer::set_variable("percent", &format!("{}%", percent));
+    runtime::timer::set_variable("percent", &format!("{}%", percent));
+}
+
+fn handle_stats(settings: &dyn SplitterSettings, state: &mut AutoSplitterState, e: &Env) {
+    let vars = settings.get_timer_variables();
+    if !(vars.fleas || vars.room || vars.room_time || vars.stats) {
+        return;
+    }
+
+    let Env { mem, gm, pd } = e;
+
+    // only update stats if game state is non-menu
+    let game_state: i32 = mem.deref(&gm.game_state).unwrap_or_default();
+    if !NON_MENU_GAME_STATES.contains(&game_state) {
+        return;
+    }
+
+    let scene = mem.read_string(&gm.scene_name).filter(|s| !s.is_empty());
+    let play_time: Option<f32> = mem.deref(&pd.play_time).ok();
+    if let (Some(scene), Some(play_time)) = (&scene, play_time) {
+        if state.room.as_ref().is_none_or(|(room, _)| room != scene) {
+            state.room = Some((scene.clone(), play_time));
+        }
+    }
+    let room_time = state
+        .room
+        .as_ref()
+        .zip(play_time)
+        .map(|((_, entered), now)| format!("{:.1}", now - entered));
+
+    let int = |p| mem.deref::<i32>(p).ok().map(|i| i.to_string());
+    let mut stats = Vec::new();
+    if vars.fleas {
+        stats.push(("fleas", fleas_rescued(Some(e)).map(|i| i.to_string())));
+    }
+    if vars.room {
+        stats.push(("scene", scene));
+    }
+    if vars.room_time {
+        stats.push(("room time", room_time));
+    }
+    if vars.stats {
+        stats.extend([
+            ("masks", int(&pd.max_health_base)),
+            ("spool", int(&pd.silk_max)),
+            ("rosaries", int(&pd.geo)),
+            ("shell shards", int(&pd.shell_shards)),
+            ("crest", mem.read_string(&pd.current_crest_id)),
+            ("respawn scene", mem.read_string(&pd.respawn_scene)),
+            ("needle", int(&pd.nail_upgrades)),
+            ("tool pouch", int(&pd.tool_pouch_upgrades)),
+            ("crafting kit", int(&pd.tool_kit_upgrades)),
+        ]);
+    }
+    for (key, value) in stats {
+        let Some(value) = value else {
+            continue;
+        };
+        if state.stats.get(key) != Some(&value) {
+            runtime::timer::set_variable(key, &value);
+            state.stats.insert(key, value);
+        }
+    }
+}
+
+/// Shows the build of the game in the `game version` variable, once it's known,
+/// and again whenever the variable is turned on.
+fn handle_game_version(
+    settings: &dyn SplitterSettings,
+    state: &mut AutoSplitterState,
+    version: Option<&GameVersion>,
+) {
+    if !settings.get_timer_variables().game_version {
+        return;
+    }
+    let version = version.map_or(DASH, |v| v.0.as_str());
+    if state.stats.get("game version").is_none_or(|v| v != version) {
+        runtime::timer::set_variable("game version", version);
+        state.stats.insert("game version", version.to_string());
+    }
+}
+
+fn handle_attempt_stats(settings: &dyn SplitterSettings, state: &mut AutoSplitterState) {
+    if !settings.get_timer_variables().attempts {
+        return;
+    }
+    // only show attempt stats while running, for the segment the run is in
+    let index = state
+        .split_index
+        .filter(|_| !is_timer_state_between_runs(state.timer_state));
+    if state.attempt_stats_index == Some(index) {
+        return;
+    }
+    state.attempt_stats_index = Some(index);
+    let [reaching_key, reset_rate_key] = ATTEMPT_VARIABLES;
+    let Some(i) = index else {
+        runtime::timer::set_variable(reaching_key, DASH);
+        runtime::timer::set_variable(reset_rate_key, DASH);
+        return;
+    };
+    let splits = fingerprint(&state.comparison_splits);
+    let reaching = state.history.reaching(&splits, i);
+    let resets = state.history.resets(&splits, i);
+    runtime::timer::set_variable_int(reaching_key, reaching as i64);
+    if let Some(rate) = (resets * 100).checked_div(reaching) {
+        runtime::timer::set_variable(reset_rate_key, &format!("{}%", rate));
+    } else {
+        runtime::timer::set_variable(reset_rate_key, DASH);
+    }
 }
 
 // --------------------------------------------------------
 
+/// Stores the [options_str] of a radio button option under `key`, for a [StoreWidget] impl,
+/// producing whether it changed.
+pub fn insert_option_str<T: RadioButtonOptions + PartialEq>(
+    value: &T,
+    settings_map: &asr::settings::Map,
+    key: &str,
+) -> bool {
+    let new_s = options_str(value);
+    if settings_map
+        .get(key)
+        .is_some_and(|old_v| old_v.get_string().is_some_and(|old_s| old_s == new_s))
+    {
+        return false;
+    }
+    settings_map.insert(key, new_s);
+    true
+}
+
 pub fn is_timer_state_between_runs(s: TimerState) -> bool {
     s == TimerState::NotRunning || s == TimerState::Ended
 }
@@ -947,11 +1743,3 @@ pub fn is_timer_state_between_runs(s: TimerState) -> bool {
 pub fn str_take_right(s: &str, n: usize) -> &str {
     s.split_at(s.len().saturating_sub(n)).1
 }
-
-fn delta_string(i: i64) -> String {
-    if i == 0 {
-        "0".into()
-    } else {
-        format!("{:+}", i)
-    }
-}
@@ -0,0 +1,220 @@
+//! Deciding whether game time should be paused on a tick, and why.
+
+use alloc::{string::String, vec, vec::Vec};
+use asr::settings::Gui;
+use core::fmt;
+use ugly_widget::{radio_button::RadioButtonOptions, store::StoreWidget};
+
+use crate::{
+    insert_option_str,
+    silksong_memory::{
+        GAME_STATE_CUTSCENE, GAME_STATE_ENTERING_LEVEL, GAME_STATE_EXITING_LEVEL,
+        GAME_STATE_LOADING, GAME_STATE_PLAYING, HERO_TRANSITION_STATE_WAITING_TO_ENTER_LEVEL,
+        MENU_TITLE, QUIT_TO_MENU, UI_STATE_CUTSCENE, UI_STATE_PAUSED, UI_STATE_PLAYING,
+    },
+};
+
+/// Everything that load removal looks at on one tick.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct LoadRemovalInputs {
+    pub game_state: i32,
+    pub ui_state: i32,
+    pub accepting_input: bool,
+    pub hero_transition_state: i32,
+    pub scene_load_null: bool,
+    pub scene_load_activation_allowed: bool,
+    pub is_inventory_open: bool,
+    pub scene_name: String,
+    pub next_scene: String,
+    /// Loading out of the opening cutscene, which shouldn't count as a load
+    pub mms_room_dupe: bool,
+    /// Loading into a save file from the main menu, until the game is playing
+    pub look_for_teleporting: bool,
+    /// Fading out and back in after hitting a hazard
+    pub hazard_respawning: bool,
+    /// Riding the bellway or the ventrica, or teleporting with the Beastling Call
+    pub fast_travelling: bool,
+    /// Checking the tilemap around Hornet while a scene is being set up
+    pub tilemap_rebuilding: bool,
+}
+
+/// Which rule set decides what counts as removed time, depending on the category.
+#[derive(Clone, Copy, Debug, Default, Eq, Gui, PartialEq, RadioButtonOptions)]
+pub enum LoadRemovalMode {
+    /// Off
+    ///
+    /// Never pauses game time, so game time is the same as real time
+    Off,
+    /// Standard
+    ///
+    /// Pauses game time for loads, scene transitions, and menus
+    #[default]
+    Standard,
+    /// Standard without Menus
+    ///
+    /// Like Standard, but keeps game time running in the pause menu
+    /// and while quitting to the main menu
+    StandardWithoutMenus,
+    /// Strict Transitions Only
+    ///
+    /// Only pauses game time while loading from one scene into another
+    StrictTransitionsOnly,
+}
+
+impl LoadRemovalMode {
+    /// Whether game time should be paused for the `cause` under this rule set.
+    pub fn removes(&self, cause: PauseCause) -> bool {
+        match self {
+            LoadRemovalMode::Off => false,
+            LoadRemovalMode::Standard => true,
+            LoadRemovalMode::StandardWithoutMenus => {
+                !matches!(cause, PauseCause::UiNotPlaying | PauseCause::LoadingMenu)
+            }
+            LoadRemovalMode::StrictTransitionsOnly => matches!(
+                cause,
+                PauseCause::ExitingLevel
+                    | PauseCause::Loading
+                    | PauseCause::WaitingToEnterLevel
+                    | PauseCause::SceneTransition
+            ),
+        }
+    }
+}
+
+impl StoreWidget for LoadRemovalMode {
+    fn insert_into(&self, settings_map: &asr::settings::Map, key: &str) -> bool {
+        insert_option_str(self, settings_map, key)
+    }
+}
+
+/// Which causes to pause game time for, depending on what the category allows.
+///
+/// The hazard respawn, fast travel, and tilemap pauses are off by default,
+/// since they remove time that Standard load removal has always counted.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct LoadRemovalOptions {
+    pub mode: LoadRemovalMode,
+    pub hazard_respawns: bool,
+    pub fast_travel: bool,
+    pub tilemap: bool,
+}
+
+impl LoadRemovalOptions {
+    /// Whether game time should be paused for the `cause`, with the mode and the checkboxes.
+    pub fn removes(&self, cause: PauseCause) -> bool {
+        self.mode.removes(cause)
+            && match cause {
+                PauseCause::HazardRespawn => self.hazard_respawns,
+                PauseCause::FastTravel => self.fast_travel,
+                PauseCause::TilemapRebuilding => self.tilemap,
+                _ => true,
+            }
+    }
+}
+
+/// Why game time is paused.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PauseCause {
+    LookForTeleporting,
+    /// Playing or entering a level, but with a menu or something else in front of it
+    UiNotPlaying,
+    NotAcceptingInput,
+    ExitingLevel,
+    Loading,
+    WaitingToEnterLevel,
+    LoadingMenu,
+    SceneTransition,
+    HazardRespawn,
+    FastTravel,
+    TilemapRebuilding,
+}
+
+impl fmt::Display for PauseCause {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            PauseCause::LookForTeleporting => "loading a save file",
+            PauseCause::UiNotPlaying => "ui not playing",
+            PauseCause::NotAcceptingInput => "not accepting input",
+            PauseCause::ExitingLevel => "exiting level",
+            PauseCause::Loading => "loading",
+            PauseCause::WaitingToEnterLevel => "waiting to enter level",
+            PauseCause::LoadingMenu => "loading the menu",
+            PauseCause::SceneTransition => "scene transition",
+            PauseCause::HazardRespawn => "hazard respawn",
+            PauseCause::FastTravel => "fast travel",
+            PauseCause::TilemapRebuilding => "tilemap rebuilding",
+        })
+    }
+}
+
+impl PauseCause {
+    /// Every cause, in the order that decides which one wins.
+    pub const ALL: [PauseCause; 11] = [
+        PauseCause::LookForTeleporting,
+        PauseCause::UiNotPlaying,
+        PauseCause::NotAcceptingInput,
+        PauseCause::ExitingLevel,
+        PauseCause::Loading,
+        PauseCause::WaitingToEnterLevel,
+        PauseCause::LoadingMenu,
+        PauseCause::SceneTransition,
+        PauseCause::HazardRespawn,
+        PauseCause::FastTravel,
+        PauseCause::TilemapRebuilding,
+    ];
+
+    fn applies(self, i: &LoadRemovalInputs) -> bool {
+        let loading_menu = (i.scene_name == QUIT_TO_MENU)
+            || (i.scene_name != MENU_TITLE
+                && (i.next_scene.is_empty() || i.next_scene == MENU_TITLE));
+
+        match self {
+            PauseCause::LookForTeleporting => i.look_for_teleporting,
+            PauseCause::UiNotPlaying => {
+                (i.game_state == GAME_STATE_PLAYING || i.game_state == GAME_STATE_ENTERING_LEVEL)
+                    && i.ui_state != UI_STATE_PLAYING
+            }
+            PauseCause::NotAcceptingInput => {
+                i.game_state != GAME_STATE_PLAYING
+                    && i.game_state != GAME_STATE_CUTSCENE
+                    && !i.accepting_input
+                    && !i.mms_room_dupe
+            }
+            PauseCause::ExitingLevel => {
+                i.game_state == GAME_STATE_EXITING_LEVEL
+                    && (i.scene_load_null || i.scene_load_activation_allowed)
+                    && !i.is_inventory_open
+                    && !i.mms_room_dupe
+            }
+            PauseCause::Loading => i.game_state == GAME_STATE_LOADING,
+            PauseCause::WaitingToEnterLevel => {
+                i.hero_transition_state == HERO_TRANSITION_STATE_WAITING_TO_ENTER_LEVEL
+                    && !i.is_inventory_open
+            }
+            PauseCause::LoadingMenu => {
+                i.ui_state != UI_STATE_PLAYING && i.next_scene != i.scene_name && loading_menu
+            }
+            PauseCause::SceneTransition => {
+                i.ui_state != UI_STATE_PLAYING
+                    && i.next_scene != i.scene_name
+                    && !loading_menu
+                    && i.ui_state != UI_STATE_PAUSED
+                    && i.ui_state != UI_STATE_CUTSCENE
+                    && !i.next_scene.is_empty()
+            }
+            PauseCause::HazardRespawn => i.game_state == GAME_STATE_PLAYING && i.hazard_respawning,
+            PauseCause::FastTravel => i.fast_travelling && !i.hazard_respawning,
+            PauseCause::TilemapRebuilding => i.tilemap_rebuilding,
+        }
+    }
+}
+
+/// Produces why game time should be paused, or None if it should be running.
+///
+/// When more than one cause applies, the first one in the order of [PauseCause] wins,
+/// skipping the ones that the `options` don't remove.
+pub fn pause_cause(i: &LoadRemovalInputs, options: &LoadRemovalOptions) -> Option<PauseCause> {
+    PauseCause::ALL
+        .into_iter()
+        .find(|&cause| options.removes(cause) && cause.applies(i))
+}
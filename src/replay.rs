@@ -0,0 +1,317 @@
+//! Feeds a recorded [trace](crate::trace) back through the per-tick handlers on the host,
+//! reporting what the timer would have done at each tick.
+
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::fmt;
+use ugly_widget::radio_button::options_str;
+
+use crate::{
+    counter::{
+        CounterComparison, HitOptions, SegmentCounter, DEATH_KEYS, DEATH_VARIABLES, HIT_KEYS,
+        HIT_VARIABLES,
+    },
+    handle_tick,
+    history::AttemptHistory,
+    load_removal::{LoadRemovalOptions, PauseCause},
+    runtime::host::{reset_host_timer, with, TimerEvent},
+    silksong_memory::{Env, GameManagerPointers, PlayerDataPointers, SceneStore},
+    snapshot::Snapshot,
+    splits::{Split, SplitArgs},
+    trace::{parse_line, TraceHeader, TraceLine, TraceTick},
+    AutoSplitterState, SplitterSettings, TimerVariables,
+};
+
+/// The ticks per second that traces are recorded at
+pub const TICKS_PER_SECOND: u64 = 120;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ReplayEventKind {
+    Start,
+    /// Split on the split at `index` in the splits list
+    Split {
+        index: u64,
+        split: Split,
+    },
+    /// Skipped the split at `index` in the splits list
+    Skip {
+        index: u64,
+        split: Split,
+    },
+    Reset,
+    /// Paused game time, or kept it paused for a different cause
+    Pause {
+        cause: PauseCause,
+    },
+    Resume,
+    /// Counted a hit, bringing the total to `hits`
+    Hit {
+        hits: i64,
+    },
+}
+
+impl fmt::Display for ReplayEventKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReplayEventKind::Start => write!(f, "start"),
+            ReplayEventKind::Split { index, split } => {
+                write!(f, "split {} ({})", index, options_str(split))
+            }
+            ReplayEventKind::Skip { index, split } => {
+                write!(f, "skip {} ({})", index, options_str(split))
+            }
+            ReplayEventKind::Reset => write!(f, "reset"),
+            ReplayEventKind::Pause { cause } => write!(f, "pause game time ({})", cause),
+            ReplayEventKind::Resume => write!(f, "resume game time"),
+            ReplayEventKind::Hit { hits } => write!(f, "hit ({} total)", hits),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReplayEvent {
+    pub tick: u64,
+    pub kind: ReplayEventKind,
+}
+
+impl fmt::Display for ReplayEvent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "tick {}: {}", self.tick, self.kind)
+    }
+}
+
+// --------------------------------------------------------
+
+/// A recording, from its header up until the next header or the end of the log.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Trace {
+    pub header: TraceHeader,
+    pub ticks: Vec<TraceTick>,
+}
+
+impl Trace {
+    /// Parses the first recording in `log`, skipping lines that aren't part of a trace.
+    pub fn parse(log: &str) -> Result<Trace, String> {
+        let mut trace: Option<Trace> = None;
+        for (i, line) in log.lines().enumerate() {
+            let Some(parsed) = parse_line(line) else {
+                continue;
+            };
+            match (
+                parsed.map_err(|e| format!("line {}: {}", i + 1, e))?,
+                &mut trace,
+            ) {
+                (TraceLine::Header(header), None) => {
+                    trace = Some(Trace {
+                        header,
+                        ticks: Vec::new(),
+                    })
+                }
+                (TraceLine::Header(_), Some(_)) => break,
+                (TraceLine::Tick(tick), Some(t)) => t.ticks.push(tick),
+                (TraceLine::Tick(_), None) => {
+                    return Err(format!("line {}: trace tick before the header", i + 1))
+                }
+            }
+        }
+        trace.ok_or_else(|| "no trace header found".to_string())
+    }
+}
+
+impl SplitterSettings for TraceHeader {
+    fn get_hit_counter(&self) -> bool {
+        self.hit_counter
+    }
+    fn get_death_counter(&self) -> bool {
+        false
+    }
+    fn get_hit_options(&self) -> HitOptions {
+        self.hit_options
+    }
+    fn get_counter_comparison(&self) -> CounterComparison {
+        CounterComparison::default()
+    }
+    fn get_splits_len(&self) -> usize {
+        self.splits.len()
+    }
+    fn get_split(&self, i: u64) -> Option<Split> {
+        self.splits.get(i as usize).cloned()
+    }
+    fn get_split_args(&self, i: u64) -> Option<&SplitArgs> {
+        self.split_args.get(i as usize)
+    }
+    fn get_load_removal_options(&self) -> LoadRemovalOptions {
+        self.load_removal
+    }
+    fn get_timer_variables(&self) -> TimerVariables {
+        TimerVariables::default()
+    }
+    fn set_comparison(&self, _: &str, _: &[i64]) {}
+    fn set_comparison_splits(&self, _: &[String]) {}
+    fn set_attempt_history(&self, _: &AttemptHistory) {}
+}
+
+/// The number of segments in the run, not counting the first split that starts it.
+fn segment_count(header: &TraceHeader) -> u64 {
+    header.splits.len().saturating_sub(1) as u64
+}
+
+/// Runs the handlers on every tick of the `trace`, as if it were the game.
+pub fn replay(trace: &Trace) -> Vec<ReplayEvent> {
+    let settings = &trace.header;
+    reset_host_timer(segment_count(settings));
+
+    let gm = GameManagerPointers::new();
+    let pd = PlayerDataPointers::new();
+    let mut snapshot = Snapshot::new();
+    let mut scene_store = SceneStore::new();
+    let mut state = AutoSplitterState::new(
+        SegmentCounter::new(HIT_KEYS, HIT_VARIABLES),
+        SegmentCounter::new(DEATH_KEYS, DEATH_VARIABLES),
+    );
+
+    let mut events = Vec::new();
+    let mut lines = trace.ticks.iter().peekable();
+    let last_tick = trace.ticks.last().map_or(0, |t| t.tick);
+    for tick in 0..=last_tick {
+        while let Some(line) = lines.next_if(|l| l.tick <= tick) {
+            line.apply(&mut snapshot);
+        }
+
+        let paused_before = with(|t| t.game_time_paused);
+        let cause_before = state.pause_cause;
+        let hits_before = state.hits.total;
+
+        let env = Env::new(&snapshot, &pd, &gm);
+        handle_tick(settings, &mut state, &env, &mut scene_store);
+
+        let (timer_events, paused_after) = with(|t| {
+            let es: Vec<TimerEvent> = t.events.drain(..).collect();
+            (es, t.game_time_paused)
+        });
+        let split_at = |index: u64| settings.get_split(index).unwrap_or_default();
+        let mut push = |kind| events.push(ReplayEvent { tick, kind });
+        for e in timer_events {
+            push(match e {
+                TimerEvent::Start => ReplayEventKind::Start,
+                TimerEvent::Split(index) => ReplayEventKind::Split {
+                    index,
+                    split: split_at(index),
+                },
+                TimerEvent::SkipSplit(index) => ReplayEventKind::Skip {
+                    index,
+                    split: split_at(index),
+                },
+                TimerEvent::Reset => ReplayEventKind::Reset,
+            });
+        }
+        if state.hits.total > hits_before {
+            push(ReplayEventKind::Hit {
+                hits: state.hits.total,
+            });
+        }
+        match state.pause_cause {
+            Some(cause) if paused_after && (!paused_before || cause_before != Some(cause)) => {
+                push(ReplayEventKind::Pause { cause })
+            }
+            _ if paused_before && !paused_after => push(ReplayEventKind::Resume),
+            _ => (),
+        }
+    }
+    events
+}
+
+// --------------------------------------------------------
+
+/// A stretch of ticks where game time was paused, from `start` up until `end`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PausedInterval {
+    pub start: u64,
+    pub end: u64,
+    pub cause: PauseCause,
+}
+
+impl fmt::Display for PausedInterval {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "ticks {}..{}: {} ({})",
+            self.start,
+            self.end,
+            ticks_to_string(self.end - self.start),
+            self.cause
+        )
+    }
+}
+
+/// How load removal went over the part of a replay that the timer was running for.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct LoadRemovalReport {
+    pub real_ticks: u64,
+    pub game_ticks: u64,
+    pub paused: Vec<PausedInterval>,
+}
+
+/// Keeps track of what the timer is doing while going through the replay events.
+#[derive(Default)]
+struct LoadRemovalTally {
+    report: LoadRemovalReport,
+    running_since: Option<u64>,
+    paused_since: Option<(u64, PauseCause)>,
+}
+
+impl LoadRemovalTally {
+    fn pause(&mut self, tick: u64, cause: PauseCause) {
+        if self.running_since.is_some() {
+            self.resume(tick);
+            self.paused_since = Some((tick, cause));
+        }
+    }
+
+    fn resume(&mut self, tick: u64) {
+        if let Some((start, cause)) = self.paused_since.take() {
+            self.report.paused.push(PausedInterval {
+                start,
+                end: tick,
+                cause,
+            });
+        }
+    }
+
+    fn stop(&mut self, tick: u64) {
+        self.resume(tick);
+        if let Some(start) = self.running_since.take() {
+            self.report.real_ticks += tick - start;
+        }
+    }
+}
+
+/// Replays the `trace`, and reports on every stretch of game time that was removed, and why.
+pub fn simulate_load_removal(trace: &Trace) -> LoadRemovalReport {
+    let segment_count = segment_count(&trace.header);
+    let mut tally = LoadRemovalTally::default();
+    for ReplayEvent { tick, kind } in replay(trace) {
+        match kind {
+            ReplayEventKind::Start => tally.running_since = Some(tick),
+            ReplayEventKind::Split { index, .. } if segment_count <= index => tally.stop(tick),
+            ReplayEventKind::Reset => tally.stop(tick),
+            ReplayEventKind::Pause { cause } => tally.pause(tick, cause),
+            ReplayEventKind::Resume => tally.resume(tick),
+            _ => (),
+        }
+    }
+    tally.stop(trace.ticks.last().map_or(0, |t| t.tick));
+
+    let mut report = tally.report;
+    let paused_ticks: u64 = report.paused.iter().map(|p| p.end - p.start).sum();
+    report.game_ticks = report.real_ticks - paused_ticks;
+    report
+}
+
+/// Formats a number of ticks as seconds.
+pub fn ticks_to_string(ticks: u64) -> String {
+    format!("{:.3}s", ticks as f64 / TICKS_PER_SECOND as f64)
+}
@@ -0,0 +1,173 @@
+//! The parts of the auto splitting runtime that the per-tick handlers talk to.
+//!
+//! On wasm these forward to `asr`.
+//! On the host there is no runtime to talk to, so they drive a stand-in [host::HostTimer] instead,
+//! which is what lets a replay run the handlers without LiveSplit or the game.
+
+#[cfg(target_os = "unknown")]
+pub fn print_message(text: &str) {
+    asr::print_message(text)
+}
+
+#[cfg(not(target_os = "unknown"))]
+pub fn print_message(_text: &str) {}
+
+#[cfg(target_os = "unknown")]
+pub mod timer {
+    #[cfg(feature = "split-index")]
+    pub use asr::timer::{current_split_index, segment_splitted};
+    pub use asr::timer::{
+        pause_game_time, reset, resume_game_time, set_variable, skip_split, split, start, state,
+    };
+
+    pub fn set_variable_int(key: &str, value: impl Into<i64>) {
+        let value: i64 = value.into();
+        asr::timer::set_variable_int(key, value)
+    }
+}
+
+#[cfg(not(target_os = "unknown"))]
+pub mod timer {
+    use alloc::string::ToString;
+    use asr::timer::TimerState;
+
+    use super::host::{with, TimerEvent};
+
+    pub fn state() -> TimerState {
+        with(|t| t.state)
+    }
+
+    pub fn start() {
+        with(|t| {
+            if t.state == TimerState::NotRunning {
+                t.state = TimerState::Running;
+                t.split_index = Some(0);
+                t.game_time_paused = false;
+                t.events.push(TimerEvent::Start);
+            }
+        })
+    }
+
+    pub fn split() {
+        with(|t| {
+            if t.state == TimerState::Running || t.state == TimerState::Paused {
+                let i = t.split_index.unwrap_or_default() + 1;
+                t.split_index = Some(i);
+                t.segments_splitted.push(true);
+                if t.segment_count <= i {
+                    t.state = TimerState::Ended;
+                }
+                t.events.push(TimerEvent::Split(i));
+            }
+        })
+    }
+
+    pub fn skip_split() {
+        with(|t| {
+            let i = t.split_index.unwrap_or_default() + 1;
+            // the last split can't be skipped
+            if (t.state == TimerState::Running || t.state == TimerState::Paused)
+                && i < t.segment_count
+            {
+                t.split_index = Some(i);
+                t.segments_splitted.push(false);
+                t.events.push(TimerEvent::SkipSplit(i));
+            }
+        })
+    }
+
+    pub fn reset() {
+        with(|t| {
+            if t.state != TimerState::NotRunning {
+                t.state = TimerState::NotRunning;
+                t.split_index = None;
+                t.segments_splitted.clear();
+                t.game_time_paused = false;
+                t.events.push(TimerEvent::Reset);
+            }
+        })
+    }
+
+    pub fn pause_game_time() {
+        with(|t| t.game_time_paused = true)
+    }
+
+    pub fn resume_game_time() {
+        with(|t| t.game_time_paused = false)
+    }
+
+    pub fn set_variable(key: &str, value: &str) {
+        with(|t| {
+            t.variables.insert(key.to_string(), value.to_string());
+        })
+    }
+
+    pub fn set_variable_int(key: &str, value: impl Into<i64>) {
+        set_variable(key, &value.into().to_string())
+    }
+
+    #[cfg(feature = "split-index")]
+    pub fn current_split_index() -> Option<u64> {
+        with(|t| t.split_index)
+    }
+
+    #[cfg(feature = "split-index")]
+    pub fn segment_splitted(idx: u64) -> Option<bool> {
+        with(|t| t.segments_splitted.get(idx as usize).copied())
+    }
+}
+
+#[cfg(not(target_os = "unknown"))]
+pub mod host {
+    use alloc::{collections::BTreeMap, string::String, vec::Vec};
+    use asr::timer::TimerState;
+    use core::cell::RefCell;
+
+    /// A timer action that took effect on the [HostTimer],
+    /// with the split index it moved to.
+    #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+    pub enum TimerEvent {
+        Start,
+        Split(u64),
+        SkipSplit(u64),
+        Reset,
+    }
+
+    /// Behaves like the LiveSplit timer for a run with `segment_count` segments.
+    pub struct HostTimer {
+        pub state: TimerState,
+        pub split_index: Option<u64>,
+        pub segments_splitted: Vec<bool>,
+        pub segment_count: u64,
+        pub game_time_paused: bool,
+        pub variables: BTreeMap<String, String>,
+        pub events: Vec<TimerEvent>,
+    }
+
+    impl HostTimer {
+        pub fn new(segment_count: u64) -> HostTimer {
+            HostTimer {
+                state: TimerState::NotRunning,
+                split_index: None,
+                segments_splitted: Vec::new(),
+                segment_count,
+                game_time_paused: false,
+                variables: BTreeMap::new(),
+                events: Vec::new(),
+            }
+        }
+    }
+
+    std::thread_local! {
+        static HOST_TIMER: RefCell<HostTimer> = RefCell::new(HostTimer::new(0));
+    }
+
+    pub fn with<R>(f: impl FnOnce(&mut HostTimer) -> R) -> R {
+        HOST_TIMER.with(|t| f(&mut t.borrow_mut()))
+    }
+
+    /// Replaces the timer on this thread with a fresh one.
+    pub fn reset_host_timer(segment_count: u64) {
+        with(|t| *t = HostTimer::new(segment_count))
+    }
+}
@@ -1,9 +1,14 @@
-use core::mem;
+use core::{
+    cell::{Cell, RefCell},
+    mem,
+};
 
 use alloc::format;
 use alloc::{
     boxed::Box,
+    collections::BTreeMap,
     string::{String, ToString},
+    vec,
     vec::Vec,
 };
 use asr::{
@@ -11,9 +16,11 @@ use asr::{
     game_engine::unity::mono::{self, UnityPointer},
     timer::TimerState,
     watcher::Pair,
-    Address64, Process,
+    Address, Address64, Process,
 };
-use bytemuck::CheckedBitPattern;
+use bytemuck::{checked, CheckedBitPattern, NoUninit, Zeroable};
+
+use crate::runtime;
 
 // --------------------------------------------------------
 
@@ -110,11 +117,12 @@ pub const HERO_TRANSITION_STATE_WAITING_TO_ENTER_LEVEL: i32 = 2;
 pub struct StringListOffsets {
     string_len: u64,
     string_contents: u64,
-    /*
+    /// `List<T>._items`
     list_array: u64,
+    /// `List<T>._size`
+    list_size: u64,
     array_len: u64,
     array_contents: u64,
-    */
 }
 
 impl StringListOffsets {
@@ -122,15 +130,195 @@ impl StringListOffsets {
         StringListOffsets {
             string_len: 0x10,
             string_contents: 0x14,
-            /*
             list_array: 0x10,
+            list_size: 0x18,
             array_len: 0x18,
             array_contents: 0x20,
-            */
+        }
+    }
+
+    /// The offsets for the `version` of the game, with the [OFFSET_OVERRIDES] for that version.
+    fn for_version(version: Option<&GameVersion>) -> StringListOffsets {
+        let mut o = StringListOffsets::new();
+        apply_offset_overrides(
+            version,
+            "StringListOffsets",
+            &mut [
+                ("string_len", &mut o.string_len),
+                ("string_contents", &mut o.string_contents),
+                ("list_array", &mut o.list_array),
+                ("list_size", &mut o.list_size),
+                ("array_len", &mut o.array_len),
+                ("array_contents", &mut o.array_contents),
+            ],
+        );
+        o
+    }
+}
+
+/// The layout of a Mono `Dictionary<K, V>` or `HashSet<T>` with reference keys,
+/// whose `_entries` arrays hold entries of `hashCode`, `next`, the key, and then the value.
+pub struct DictionaryOffsets {
+    /// `Dictionary<K, V>._entries`
+    dictionary_entries: u64,
+    /// `Dictionary<K, V>._version`, which changes whenever an entry is added or removed
+    dictionary_version: u64,
+    /// `HashSet<T>._entries`
+    hash_set_entries: u64,
+    /// The key, or the element of a `HashSet<T>`
+    entry_key: u64,
+    /// The value, after the key
+    entry_value: u64,
+}
+
+impl DictionaryOffsets {
+    fn new() -> DictionaryOffsets {
+        DictionaryOffsets {
+            dictionary_entries: 0x18,
+            dictionary_version: 0x4c,
+            hash_set_entries: 0x18,
+            entry_key: 0x8,
+            entry_value: 0x10,
+        }
+    }
+
+    /// The offsets for the `version` of the game, with the [OFFSET_OVERRIDES] for that version.
+    fn for_version(version: Option<&GameVersion>) -> DictionaryOffsets {
+        let mut o = DictionaryOffsets::new();
+        apply_offset_overrides(
+            version,
+            "DictionaryOffsets",
+            &mut [
+                ("dictionary_entries", &mut o.dictionary_entries),
+                ("dictionary_version", &mut o.dictionary_version),
+                ("hash_set_entries", &mut o.hash_set_entries),
+                ("entry_key", &mut o.entry_key),
+                ("entry_value", &mut o.entry_value),
+            ],
+        );
+        o
+    }
+}
+
+/// The layout of a `PersistentItemData<T>`, one item of a SceneData collection.
+///
+/// The items are reached from `GameManager.sceneData` at `0x118`, a `SceneData` whose
+/// `persistentBools` at `0x10` and `persistentInts` at `0x18` are the collections,
+/// as dissected in `Silksong-Mono-dissector.TXT`.
+/// Each collection's `scenes` is a `Dictionary<string, Dictionary<string, PersistentItemData<T>>>`
+/// from the scene name to the scene's items by `ID`.
+/// The dissector stops at the collections, so `scenes` is found by name, and an item is a
+/// class whose `ID` and `SceneName` strings come right after the object header, then its `Value`.
+/// [Memory::find_scene_item] checks the `ID` and `SceneName` of each item it finds,
+/// so that if a build lays the item out differently, reading fails rather than reading the wrong bytes.
+pub struct SceneDataOffsets {
+    /// `PersistentItemData<T>.ID`
+    item_id: u64,
+    /// `PersistentItemData<T>.SceneName`
+    item_scene_name: u64,
+    /// `PersistentItemData<T>.Value`
+    item_value: u64,
+}
+
+impl SceneDataOffsets {
+    fn new() -> SceneDataOffsets {
+        SceneDataOffsets {
+            item_id: 0x10,
+            item_scene_name: 0x18,
+            item_value: 0x20,
+        }
+    }
+
+    /// The offsets for the `version` of the game, with the [OFFSET_OVERRIDES] for that version.
+    fn for_version(version: Option<&GameVersion>) -> SceneDataOffsets {
+        let mut o = SceneDataOffsets::new();
+        apply_offset_overrides(
+            version,
+            "SceneDataOffsets",
+            &mut [
+                ("item_id", &mut o.item_id),
+                ("item_scene_name", &mut o.item_scene_name),
+                ("item_value", &mut o.item_value),
+            ],
+        );
+        o
+    }
+}
+
+/// Where a quest's value was found in the `_entries` array of QuestCompletionData's
+/// `RuntimeData`, which holds for as long as the Dictionary has the same `_version`
+/// and `_entries`, since changing a value in place writes it to the same entry.
+struct QuestValue {
+    version: i32,
+    entries: Address64,
+    /// The address of the value, or None if the Dictionary doesn't have the quest
+    value: Option<Address64>,
+}
+
+/// Where an item of a SceneData collection was found, which holds for as long as the
+/// collection has the same `scenes` Dictionary with the same `_version`,
+/// and the scene's Dictionary of items has the same `_version`,
+/// since changing a value in place doesn't move the item.
+struct SceneItem {
+    scenes: Address64,
+    scenes_version: i32,
+    /// The scene's Dictionary of items and its `_version`, or None if `scenes` doesn't have the scene
+    items: Option<(Address64, i32)>,
+    /// The address of the item, or None if the scene's Dictionary doesn't have it
+    item: Option<Address64>,
+}
+
+/// A `QuestCompletionData.Completion`, as it's laid out in the values of `RuntimeData`.
+#[derive(Clone, Copy, CheckedBitPattern)]
+#[repr(C)]
+struct RawQuestCompletion {
+    has_been_seen: bool,
+    is_accepted: bool,
+    _padding_0: [u8; 2],
+    completed_count: i32,
+    is_completed: bool,
+    was_ever_completed: bool,
+    _padding_1: [u8; 2],
+}
+
+/// How far along a quest is, from the game's QuestCompletionData.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct QuestCompletion {
+    pub accepted: bool,
+    /// The count the game keeps toward the quest's objective, such as items delivered
+    pub completed_count: i32,
+    pub completed: bool,
+}
+
+impl From<RawQuestCompletion> for QuestCompletion {
+    fn from(raw: RawQuestCompletion) -> Self {
+        QuestCompletion {
+            accepted: raw.is_accepted,
+            completed_count: raw.completed_count,
+            completed: raw.is_completed,
         }
     }
 }
 
+/// A `CollectableRelicsData.Data`, as it's laid out in the values of the `RuntimeData`
+/// of `PlayerData.Relics`, which is a `SerializableNamedList` like QuestCompletionData.
+///
+/// `Silksong-Mono-dissector.TXT` only has `PlayerData.Relics` itself, as a CollectableRelicsData,
+/// not its entries. This assumes the struct's fields are the bools `IsCollected`, `IsDeposited`,
+/// and `HasSeenInRelicBoard` in that order, the way the game declares them,
+/// and only `IsCollected` is read.
+#[derive(Clone, Copy, CheckedBitPattern)]
+#[repr(C)]
+struct RawRelicData {
+    is_collected: bool,
+    is_deposited: bool,
+    has_seen_in_relic_board: bool,
+}
+
+/// The most elements a collection can have before it's assumed to be garbage,
+/// like `n >= 2048` for strings.
+const MAX_COLLECTION_LEN: i32 = 0x1000;
+
 // --------------------------------------------------------
 
 pub fn attach_silksong() -> Option<Process> {
@@ -151,17 +339,220 @@ fn is_game_state_non_menu(gs: i32) -> bool {
 
 // --------------------------------------------------------
 
+/// A [UnityPointer] together with the name of the field it was declared as,
+/// so that a [GameMemory] which isn't backed by the game process can look it up by name.
+pub struct FieldPointer<const CAP: usize> {
+    pub name: &'static str,
+    /// The size of the field's type, which is how many bytes a trace records of it
+    pub width: usize,
+    pub pointer: UnityPointer<CAP>,
+    /// A path with raw offsets to use instead, where resolving `pointer` by name doesn't work
+    pub fallback: Option<UnityPointer<CAP>>,
+    /// Whether `fallback` has worked where `pointer` didn't, so it's the one to keep using
+    use_fallback: Cell<bool>,
+}
+
+impl<const CAP: usize> FieldPointer<CAP> {
+    pub fn new(name: &'static str, width: usize, pointer: UnityPointer<CAP>) -> Self {
+        FieldPointer {
+            name,
+            width,
+            pointer,
+            fallback: None,
+            use_fallback: Cell::new(false),
+        }
+    }
+
+    pub fn with_fallback(mut self, fallback: UnityPointer<CAP>) -> Self {
+        self.fallback = Some(fallback);
+        self
+    }
+}
+
+/// A named path to a field in the game's memory, independent of the pointer capacity.
+pub trait NamedPointer {
+    fn name(&self) -> &str;
+    /// The size of the field's type
+    fn width(&self) -> usize;
+    fn deref_offsets(
+        &self,
+        process: &Process,
+        module: &mono::Module,
+        image: &mono::Image,
+    ) -> Result<Address, asr::Error>;
+}
+
+impl<const CAP: usize> NamedPointer for FieldPointer<CAP> {
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    fn width(&self) -> usize {
+        self.width
+    }
+
+    fn deref_offsets(
+        &self,
+        process: &Process,
+        module: &mono::Module,
+        image: &mono::Image,
+    ) -> Result<Address, asr::Error> {
+        let Some(fallback) = &self.fallback else {
+            return self.pointer.deref_offsets(process, module, image);
+        };
+        if self.use_fallback.get() {
+            return fallback.deref_offsets(process, module, image);
+        }
+        let by_name = self.pointer.deref_offsets(process, module, image);
+        if by_name.is_ok() {
+            return by_name;
+        }
+        let by_offsets = fallback.deref_offsets(process, module, image);
+        if by_offsets.is_ok() {
+            runtime::print_message(&format!("{}: falling back to offsets", self.name));
+            self.use_fallback.set(true);
+        }
+        by_offsets
+    }
+}
+
+/// A build of the game, such as `"1.0.28324"`, from the version string that PlayerData keeps,
+/// as read by [Memory::wait_attach] or [Memory::read_build_version].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GameVersion(pub String);
+
+/// A field that a build of the game keeps somewhere else than the latest build does.
+pub struct PointerOverride {
+    /// The [GameVersion]s this applies to
+    pub versions: &'static [&'static str],
+    /// The name of the field in [GameManagerPointers] or [PlayerDataPointers], such as `"tools_version"`
+    pub field: &'static str,
+    /// The class the path starts from, such as `"GameManager"`
+    pub class: &'static str,
+    /// The path from there, which has to fit in the field's pointer
+    pub path: &'static [&'static str],
+    /// The path with raw offsets to use in place of the field's [FieldPointer::fallback],
+    /// or None for no fallback, since the latest build's offsets don't apply to this build
+    pub fallback: Option<&'static [&'static str]>,
+}
+
+/// The fields that older builds keep somewhere else, for patch-specific categories.
+/// When a patch moves a field, the old path goes here under the versions before the patch,
+/// along with the old raw offsets for a field that has a fallback,
+/// and the declared pointer is updated for the new one.
+///
+/// This is empty because every path so far comes from the one build in
+/// `Silksong-Mono-dissector.TXT`;
+/// an entry should only be added once a build has been dissected where the path differs.
+pub static POINTER_OVERRIDES: &[PointerOverride] = &[];
+
+fn pointer_overrides(
+    version: Option<&GameVersion>,
+) -> impl Iterator<Item = &'static PointerOverride> + '_ {
+    POINTER_OVERRIDES
+        .iter()
+        .filter(move |o| version.is_some_and(|v| o.versions.contains(&v.0.as_str())))
+}
+
+/// The pointer for one of the paths of the override `o`, or None if the path doesn't fit.
+fn override_pointer<const CAP: usize>(
+    o: &PointerOverride,
+    path: &'static [&'static str],
+) -> Option<UnityPointer<CAP>> {
+    if path.is_empty() || CAP < path.len() {
+        runtime::print_message(&format!(
+            "POINTER_OVERRIDES: {} needs a path of 1 to {} names, ignoring it",
+            o.field, CAP
+        ));
+        return None;
+    }
+    Some(UnityPointer::new(o.class, 0, path))
+}
+
+/// An offset in the layout of a collection or SceneData item that a build of the game
+/// has somewhere else than the latest build does, such as after an update to Unity.
+pub struct OffsetOverride {
+    /// The [GameVersion]s this applies to
+    pub versions: &'static [&'static str],
+    /// The struct the offset is in, such as `"DictionaryOffsets"`
+    pub offsets: &'static str,
+    /// The name of the offset in that struct, such as `"entry_value"`
+    pub field: &'static str,
+    pub offset: u64,
+}
+
+/// The offsets in [StringListOffsets], [DictionaryOffsets], and [SceneDataOffsets]
+/// that older builds have somewhere else, in the same way as [POINTER_OVERRIDES],
+/// and empty for the same reason.
+pub static OFFSET_OVERRIDES: &[OffsetOverride] = &[];
+
+/// Replaces each of the `fields` of the `offsets` struct that has an override for the `version`.
+fn apply_offset_overrides(
+    version: Option<&GameVersion>,
+    offsets: &str,
+    fields: &mut [(&str, &mut u64)],
+) {
+    let overrides = OFFSET_OVERRIDES
+        .iter()
+        .filter(|o| version.is_some_and(|v| o.versions.contains(&v.0.as_str())))
+        .filter(|o| o.offsets == offsets);
+    for o in overrides {
+        match fields.iter_mut().find(|(name, _)| *name == o.field) {
+            Some((_, offset)) => **offset = o.offset,
+            None => runtime::print_message(&format!(
+                "OFFSET_OVERRIDES: {} has no offset {}",
+                offsets, o.field
+            )),
+        }
+    }
+}
+
+/// Declares a struct of [FieldPointer]s, each written as `name: UnityPointer<CAP> as T = pointer`
+/// where `T` is the type of the field, optionally followed by `=> fallback`
+/// for a [FieldPointer::fallback].
 macro_rules! declare_pointers {
-    ( $g:ident { $( $f:ident : $t:ty = $e:expr ),*, } ) => {
+    ( $g:ident { $( $f:ident : UnityPointer<$cap:literal> as $t:ty = $e:expr $( => $fb:expr )? ),*, } ) => {
         pub struct $g {
-            $( pub $f : $t ),*,
+            $( pub $f : FieldPointer<$cap> ),*,
         }
 
         impl $g {
             pub fn new() -> $g {
                 $g {
-                    $( $f : $e ),*,
+                    $( $f : FieldPointer::new(stringify!($f), mem::size_of::<$t>(), $e) $( .with_fallback($fb) )? ),*,
+                }
+            }
+
+            /// The pointers for the `version` of the game,
+            /// with the [POINTER_OVERRIDES] for that version in place of the defaults,
+            /// along with their fallbacks.
+            pub fn for_version(version: Option<&GameVersion>) -> $g {
+                let mut g = $g::new();
+                for o in pointer_overrides(version) {
+                    $(
+                        if o.field == stringify!($f) {
+                            let pointer = override_pointer::<$cap>(o, o.path);
+                            let fallback = o.fallback.map(|path| override_pointer::<$cap>(o, path));
+                            match (pointer, fallback) {
+                                (Some(pointer), None) => {
+                                    g.$f.pointer = pointer;
+                                    g.$f.fallback = None;
+                                }
+                                (Some(pointer), Some(Some(fallback))) => {
+                                    g.$f.pointer = pointer;
+                                    g.$f.fallback = Some(fallback);
+                                }
+                                _ => {}
+                            }
+                        }
+                    )*
                 }
+                g
+            }
+
+            /// All of the fields, in declaration order
+            pub fn fields(&self) -> Vec<&dyn NamedPointer> {
+                vec![$( &self.$f ),*]
             }
         }
 
@@ -174,16 +565,16 @@ macro_rules! declare_pointers {
 }
 
 declare_pointers!(GameManagerPointers {
-    scene_name: UnityPointer<2> = UnityPointer::new("GameManager", 0, &["_instance", "sceneName"]),
-    next_scene_name: UnityPointer<2> = UnityPointer::new("GameManager", 0, &["_instance", "nextSceneName"]),
-    entry_gate_name: UnityPointer<2> = UnityPointer::new("GameManager", 0, &["_instance", "entryGateName"]),
-    game_state: UnityPointer<2> = UnityPointer::new("GameManager", 0, &["_instance", "<GameState>k__BackingField"]),
-    ui_state_vanilla: UnityPointer<3> = UnityPointer::new(
+    scene_name: UnityPointer<2> as Address64 = UnityPointer::new("GameManager", 0, &["_instance", "sceneName"]),
+    next_scene_name: UnityPointer<2> as Address64 = UnityPointer::new("GameManager", 0, &["_instance", "nextSceneName"]),
+    entry_gate_name: UnityPointer<2> as Address64 = UnityPointer::new("GameManager", 0, &["_instance", "entryGateName"]),
+    game_state: UnityPointer<2> as i32 = UnityPointer::new("GameManager", 0, &["_instance", "<GameState>k__BackingField"]),
+    ui_state_vanilla: UnityPointer<3> as i32 = UnityPointer::new(
         "GameManager",
         0,
         &["_instance", "<ui>k__BackingField", "uiState"],
     ),
-    accepting_input: UnityPointer<3> = UnityPointer::new(
+    accepting_input: UnityPointer<3> as bool = UnityPointer::new(
         "GameManager",
         0,
         &[
@@ -192,7 +583,7 @@ declare_pointers!(GameManagerPointers {
             "acceptingInput",
         ],
     ),
-    hazard_death: UnityPointer<4> = UnityPointer::new(
+    hazard_death: UnityPointer<4> as bool = UnityPointer::new(
         "GameManager",
         0,
         &[
@@ -202,7 +593,7 @@ declare_pointers!(GameManagerPointers {
             "hazardDeath",
         ],
     ),
-    hazard_respawning: UnityPointer<4> = UnityPointer::new(
+    hazard_respawning: UnityPointer<4> as bool = UnityPointer::new(
         "GameManager",
         0,
         &[
@@ -212,7 +603,7 @@ declare_pointers!(GameManagerPointers {
             "hazardRespawning",
         ],
     ),
-    hero_recoil_frozen: UnityPointer<4> = UnityPointer::new(
+    hero_recoil_frozen: UnityPointer<4> as bool = UnityPointer::new(
         "GameManager",
         0,
         &[
@@ -222,21 +613,37 @@ declare_pointers!(GameManagerPointers {
             "recoilFrozen",
         ],
     ),
-    hero_transition_state: UnityPointer<3> = UnityPointer::new(
+    hero_tilemap_test_active: UnityPointer<3> as bool = UnityPointer::new(
+        "GameManager",
+        0,
+        &["_instance", "<hero_ctrl>k__BackingField", "tilemapTestActive"],
+    ),
+    hero_transition_state: UnityPointer<3> as i32 = UnityPointer::new(
         "GameManager",
         0,
         &["_instance", "<hero_ctrl>k__BackingField", "transitionState"],
     ),
-    scene_load: UnityPointer<2> = UnityPointer::new(
+    scene_load: UnityPointer<2> as Address64 = UnityPointer::new(
         "GameManager",
         0,
         &["_instance", "sceneLoad"],
     ),
-    scene_load_activation_allowed: UnityPointer<3> = UnityPointer::new(
+    scene_load_activation_allowed: UnityPointer<3> as bool = UnityPointer::new(
         "GameManager",
         0,
         &["_instance", "sceneLoad", "<IsActivationAllowed>k__BackingField"],
     ),
+    // the Dictionary from scene name to a Dictionary from id to PersistentItemData
+    scene_bools: UnityPointer<4> as Address64 = UnityPointer::new(
+        "GameManager",
+        0,
+        &["_instance", "sceneData", "persistentBools", "scenes"],
+    ),
+    scene_ints: UnityPointer<4> as Address64 = UnityPointer::new(
+        "GameManager",
+        0,
+        &["_instance", "sceneData", "persistentInts", "scenes"],
+    ),
 });
 
 #[inline(never)]
@@ -244,251 +651,325 @@ fn pdp(key: &'static str) -> UnityPointer<3> {
     UnityPointer::new("GameManager", 0, &["_instance", "playerData", key])
 }
 
+/// A PlayerData field whose name is only known at runtime, such as `"hasDash"` from the split
+/// settings, named by that name itself rather than by a declared field.
+///
+/// A [UnityPointer] needs names that live for as long as the auto splitter runs,
+/// so this looks the name up in the PlayerData class instead, and owns it.
+pub struct PlayerDataField {
+    pub name: String,
+    /// The size of the type the field is read as, such as 1 for a bool
+    pub width: usize,
+    player_data: UnityPointer<2>,
+    /// The offset of the field in PlayerData, once it's been found
+    offset: Cell<Option<u32>>,
+}
+
+impl PlayerDataField {
+    pub fn new(name: &str, width: usize) -> PlayerDataField {
+        PlayerDataField {
+            name: name.to_string(),
+            width,
+            player_data: UnityPointer::new("GameManager", 0, &["_instance", "playerData"]),
+            offset: Cell::new(None),
+        }
+    }
+}
+
+impl NamedPointer for PlayerDataField {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn width(&self) -> usize {
+        self.width
+    }
+
+    fn deref_offsets(
+        &self,
+        process: &Process,
+        module: &mono::Module,
+        image: &mono::Image,
+    ) -> Result<Address, asr::Error> {
+        let offset = match self.offset.get() {
+            Some(offset) => offset,
+            None => {
+                let offset = image
+                    .get_class(process, module, "PlayerData")
+                    .and_then(|c| c.get_field_offset(process, module, &self.name))
+                    .ok_or(asr::Error {})?;
+                self.offset.set(Some(offset));
+                offset
+            }
+        };
+        let player_data: Address64 = self.player_data.deref(process, module, image)?;
+        if player_data.is_null() {
+            return Err(asr::Error {});
+        }
+        Ok((player_data + offset as u64).into())
+    }
+}
+
 declare_pointers!(PlayerDataPointers {
-    disable_pause: UnityPointer<3> = pdp("disablePause"),
-    is_inventory_open: UnityPointer<3> = pdp("isInventoryOpen"),
-    health: UnityPointer<3> = pdp("health"),
-    max_health_base: UnityPointer<3> = pdp("maxHealthBase"),
-    heart_pieces: UnityPointer<3> = pdp("heartPieces"),
-    at_bench: UnityPointer<3> = pdp("atBench"),
-    respawn_scene: UnityPointer<3> = pdp("respawnScene"),
-    defeated_moss_mother: UnityPointer<3> = pdp("defeatedMossMother"),
-    has_needle_throw: UnityPointer<3> = pdp("hasNeedleThrow"),
-    has_parry: UnityPointer<3> = pdp("hasParry"),
-    has_thread_sphere: UnityPointer<3> = pdp("hasThreadSphere"),
-    has_silk_charge: UnityPointer<3> = pdp("hasSilkCharge"),
-    has_silk_bomb: UnityPointer<3> = pdp("hasSilkBomb"),
-    has_silk_boss_needle: UnityPointer<3> = pdp("hasSilkBossNeedle"),
-    has_bonebottom_simple_key: UnityPointer<3> = pdp("PurchasedBonebottomFaithToken"),
-    collected_dust_cage_key: UnityPointer<3> = pdp("CollectedDustCageKey"),
-    merchant_enclave_simple_key: UnityPointer<3> = pdp("MerchantEnclaveSimpleKey"),
-    defeated_bell_beast: UnityPointer<3> = pdp("defeatedBellBeast"),
-    bell_shrine_bone_forest: UnityPointer<3> = pdp("bellShrineBoneForest"),
-    has_dash: UnityPointer<3> = pdp("hasDash"),
-    defeated_lace1: UnityPointer<3> = pdp("defeatedLace1"),
-    bell_shrine_wilds: UnityPointer<3> = pdp("bellShrineWilds"),
-    has_brolly: UnityPointer<3> = pdp("hasBrolly"),
-    defeated_song_golem: UnityPointer<3> = pdp("defeatedSongGolem"),
-    bell_shrine_greymoor: UnityPointer<3> = pdp("bellShrineGreymoor"),
-    defeated_vampire_gnat_boss: UnityPointer<3> = pdp("defeatedVampireGnatBoss"),
-    encountered_sister_splinter: UnityPointer<3> = pdp("encounteredSplinterQueen"),
-    defeated_sister_splinter: UnityPointer<3> = pdp("defeatedSplinterQueen"),
-    has_wall_jump: UnityPointer<3> = pdp("hasWalljump"),
-    encountered_spinner: UnityPointer<3> = pdp("encounteredSpinner"),
-    spinner_defeated: UnityPointer<3> = pdp("spinnerDefeated"),
-    bell_shrine_bellhart: UnityPointer<3> = pdp("bellShrineBellhart"),
-    bell_shrine_shellwood: UnityPointer<3> = pdp("bellShrineShellwood"),
-    encountered_last_judge: UnityPointer<3> = pdp("encounteredLastJudge"),
-    defeated_last_judge: UnityPointer<3> = pdp("defeatedLastJudge"),
-    defeated_phantom: UnityPointer<3> = pdp("defeatedPhantom"),
-    act2_started: UnityPointer<3> = pdp("act2Started"),
-    encountered_cogwork_dancers: UnityPointer<3> = pdp("encounteredCogworkDancers"),
-    defeated_cogwork_dancers: UnityPointer<3> = pdp("defeatedCogworkDancers"),
-    woke_song_chevalier: UnityPointer<3> = pdp("wokeSongChevalier"),
-    encountered_song_chevalier_boss: UnityPointer<3> = pdp("encounteredSongChevalierBoss"),
-    defeated_song_chevalier_boss: UnityPointer<3> = pdp("defeatedSongChevalierBoss"),
-    completed_library_entry_battle: UnityPointer<3> = pdp("completedLibraryEntryBattle"),
-    encountered_trobbio: UnityPointer<3> = pdp("encounteredTrobbio"),
-    defeated_trobbio: UnityPointer<3> = pdp("defeatedTrobbio"),
-    encountered_tormented_trobbio: UnityPointer<3> = pdp("encounteredTormentedTrobbio"),
-    defeated_tormented_trobbio: UnityPointer<3> = pdp("defeatedTormentedTrobbio"),
-    has_harpoon_dash: UnityPointer<3> = pdp("hasHarpoonDash"),
-    hang04_battle: UnityPointer<3> = pdp("hang04Battle"),
-    defeated_lace_tower: UnityPointer<3> = pdp("defeatedLaceTower"),
-    has_melody_librarian: UnityPointer<3> = pdp("HasMelodyLibrarian"),
-    has_melody_conductor: UnityPointer<3> = pdp("HasMelodyConductor"),
-    has_melody_architect: UnityPointer<3> = pdp("HasMelodyArchitect"),
-    unlocked_melody_lift: UnityPointer<3> = pdp("UnlockedMelodyLift"),
-    nail_upgrades: UnityPointer<3> = pdp("nailUpgrades"),
-    silk_max: UnityPointer<3> = pdp("silkMax"),
-    silk_spool_parts: UnityPointer<3> = pdp("silkSpoolParts"),
-    completed_memory_reaper: UnityPointer<3> = pdp("completedMemory_reaper"),
-    completed_memory_wanderer: UnityPointer<3> = pdp("completedMemory_wanderer"),
-    completed_memory_beast: UnityPointer<3> = pdp("completedMemory_beast"),
-    completed_memory_toolmaster: UnityPointer<3> = pdp("completedMemory_toolmaster"),
-    completed_memory_witch: UnityPointer<3> = pdp("completedMemory_witch"),
-    gained_curse: UnityPointer<3> = pdp("gainedCurse"),
-    belltown_doctor_cured_curse: UnityPointer<3> = pdp("BelltownDoctorCuredCurse"),
-    belltown_doctor_convo: UnityPointer<3> = pdp("BelltownDoctorConvo"),
-    completed_memory_shaman: UnityPointer<3> = pdp("completedMemory_shaman"),
-    has_bound_crest_upgrader: UnityPointer<3> = pdp("HasBoundCrestUpgrader"),
-    current_crest_id: UnityPointer<3> = pdp("CurrentCrestID"),
-    unlocked_extra_blue_slot: UnityPointer<3> = pdp("UnlockedExtraBlueSlot"),
-    unlocked_extra_yellow_slot: UnityPointer<3> = pdp("UnlockedExtraYellowSlot"),
-    tool_pouch_upgrades: UnityPointer<3> = pdp("ToolPouchUpgrades"),
-    tool_kit_upgrades: UnityPointer<3> = pdp("ToolKitUpgrades"),
-
-    defeated_wisp_pyre_effigy: UnityPointer<3> =  pdp("defeatedWispPyreEffigy"),
-    has_slab_key_a: UnityPointer<3> =  pdp("HasSlabKeyA"),
-    has_slab_key_b: UnityPointer<3> =  pdp("HasSlabKeyB"),
-    has_slab_key_c: UnityPointer<3> =  pdp("HasSlabKeyC"),
-    encountered_first_weaver: UnityPointer<3> =  pdp("encounteredFirstWeaver"),
-    defeated_first_weaver: UnityPointer<3> = pdp("defeatedFirstWeaver"),
-    encountered_ant_trapper: UnityPointer<3> = pdp("encounteredAntTrapper"),
-    defeated_ant_trapper: UnityPointer<3> = pdp("defeatedAntTrapper"),
-
-    savedflea_ant_03: UnityPointer<3> = pdp("SavedFlea_Ant_03"),
-    savedflea_belltown_04: UnityPointer<3> = pdp("SavedFlea_Belltown_04"),
-    savedflea_bone_06: UnityPointer<3> = pdp("SavedFlea_Bone_06"),
-    savedflea_bone_east_05: UnityPointer<3> = pdp("SavedFlea_Bone_East_05"),
-    savedflea_bone_east_10_church: UnityPointer<3> = pdp("SavedFlea_Bone_East_10_Church"),
-    savedflea_bone_east_17b: UnityPointer<3> = pdp("SavedFlea_Bone_East_17b"),
-    savedflea_coral_24: UnityPointer<3> = pdp("SavedFlea_Coral_24"),
-    savedflea_coral_35: UnityPointer<3> = pdp("SavedFlea_Coral_35"),
-    savedflea_crawl_06: UnityPointer<3> = pdp("SavedFlea_Crawl_06"),
-    savedflea_dock_03d: UnityPointer<3> = pdp("SavedFlea_Dock_03d"),
-    savedflea_dock_16: UnityPointer<3> = pdp("SavedFlea_Dock_16"),
-    savedflea_dust_09: UnityPointer<3> = pdp("SavedFlea_Dust_09"),
-    savedflea_dust_12: UnityPointer<3> = pdp("SavedFlea_Dust_12"),
-    savedflea_greymoor_06: UnityPointer<3> = pdp("SavedFlea_Greymoor_06"),
-    savedflea_greymoor_15b: UnityPointer<3> = pdp("SavedFlea_Greymoor_15b"),
-    savedflea_library_01: UnityPointer<3> = pdp("SavedFlea_Library_01"),
-    savedflea_library_09: UnityPointer<3> = pdp("SavedFlea_Library_09"),
-    savedflea_peak_05c: UnityPointer<3> = pdp("SavedFlea_Peak_05c"),
-    savedflea_shadow_10: UnityPointer<3> = pdp("SavedFlea_Shadow_10"),
-    savedflea_shadow_28: UnityPointer<3> = pdp("SavedFlea_Shadow_28"),
-    savedflea_shellwood_03: UnityPointer<3> = pdp("SavedFlea_Shellwood_03"),
-    savedflea_slab_06: UnityPointer<3> = pdp("SavedFlea_Slab_06"),
-    savedflea_slab_cell: UnityPointer<3> = pdp("SavedFlea_Slab_Cell"),
-    savedflea_song_11: UnityPointer<3> = pdp("SavedFlea_Song_11"),
-    savedflea_song_14: UnityPointer<3> = pdp("SavedFlea_Song_14"),
-    savedflea_under_21: UnityPointer<3> = pdp("SavedFlea_Under_21"),
-    savedflea_under_23: UnityPointer<3> = pdp("SavedFlea_Under_23"),
-    tamed_giant_flea: UnityPointer<3> = pdp("tamedGiantFlea"),
-    met_troupe_hunter_wild: UnityPointer<3> = pdp("MetTroupeHunterWild"),
-    caravan_lech_saved: UnityPointer<3> = pdp("CaravanLechSaved"),
-
-    unlocked_aqueduct_station: UnityPointer<3> = pdp("UnlockedAqueductStation"),
-    unlocked_belltown_station: UnityPointer<3> = pdp("UnlockedBelltownStation"),
-    unlocked_boneforest_east_station: UnityPointer<3> = pdp("UnlockedBoneforestEastStation"),
-    unlocked_city_station: UnityPointer<3> = pdp("UnlockedCityStation"),
-    unlocked_coral_tower_station: UnityPointer<3> = pdp("UnlockedCoralTowerStation"),
-    unlocked_docks_station: UnityPointer<3> = pdp("UnlockedDocksStation"),
-    unlocked_greymoor_station: UnityPointer<3> = pdp("UnlockedGreymoorStation"),
-    unlocked_peak_station: UnityPointer<3> = pdp("UnlockedPeakStation"),
-    unlocked_shadow_station: UnityPointer<3> = pdp("UnlockedShadowStation"),
-    unlocked_shellwood_station: UnityPointer<3> = pdp("UnlockedShellwoodStation"),
-
-    unlocked_song_tube: UnityPointer<3> = pdp("UnlockedSongTube"),
-    unlocked_under_tube: UnityPointer<3> = pdp("UnlockedUnderTube"),
-    unlocked_city_bellway_tube: UnityPointer<3> = pdp("UnlockedCityBellwayTube"),
-    unlocked_hang_tube: UnityPointer<3> = pdp("UnlockedHangTube"),
-    unlocked_enclave_tube: UnityPointer<3> = pdp("UnlockedEnclaveTube"),
-    unlocked_arborium_tube: UnityPointer<3> = pdp("UnlockedArboriumTube"),
-
-    seen_mapper_bonetown: UnityPointer<3> = pdp("SeenMapperBonetown"),
-    seen_mapper_bone_forest: UnityPointer<3> = pdp("SeenMapperBoneForest"),
-    seen_mapper_docks: UnityPointer<3> = pdp("SeenMapperDocks"),
-    seen_mapper_wilds: UnityPointer<3> = pdp("SeenMapperWilds"),
-    seen_mapper_crawl: UnityPointer<3> = pdp("SeenMapperCrawl"),
-    seen_mapper_greymoor: UnityPointer<3> = pdp("SeenMapperGreymoor"),
-    seen_mapper_bellhart: UnityPointer<3> = pdp("SeenMapperBellhart"),
-    seen_mapper_shellwood: UnityPointer<3> = pdp("SeenMapperShellwood"),
-    seen_mapper_hunters_nest: UnityPointer<3> = pdp("SeenMapperHuntersNest"),
-    seen_mapper_judge_steps: UnityPointer<3> = pdp("SeenMapperJudgeSteps"),
-    seen_mapper_dustpens: UnityPointer<3> = pdp("SeenMapperDustpens"),
-    seen_mapper_peak: UnityPointer<3> = pdp("SeenMapperPeak"),
-    seen_mapper_shadow: UnityPointer<3> = pdp("SeenMapperShadow"),
-    seen_mapper_coral_caverns: UnityPointer<3> = pdp("SeenMapperCoralCaverns"),
-
-    has_moss_grotto_map: UnityPointer<3> = pdp("HasMossGrottoMap"),
-    has_boneforest_map: UnityPointer<3> = pdp("HasBoneforestMap"),
-    has_docks_map: UnityPointer<3> = pdp("HasDocksMap"),
-    has_wilds_map: UnityPointer<3> = pdp("HasWildsMap"),
-    has_crawl_map: UnityPointer<3> = pdp("HasCrawlMap"),
-    has_greymoor_map: UnityPointer<3> = pdp("HasGreymoorMap"),
-    has_bellhart_map: UnityPointer<3> = pdp("HasBellhartMap"),
-    has_shellwood_map: UnityPointer<3> = pdp("HasShellwoodMap"),
-    has_hunters_nest_map: UnityPointer<3> = pdp("HasHuntersNestMap"),
-    has_judge_steps_map: UnityPointer<3> = pdp("HasJudgeStepsMap"),
-    has_dustpens_map: UnityPointer<3> = pdp("HasDustpensMap"),
-    has_peak_map: UnityPointer<3> = pdp("HasPeakMap"),
-    has_swamp_map: UnityPointer<3> = pdp("HasSwampMap"),
-    has_coral_map: UnityPointer<3> = pdp("HasCoralMap"),
-
-    met_city_merchant_enclave: UnityPointer<3> = pdp("MetCityMerchantEnclave"),
-    met_sherma_enclave: UnityPointer<3> = pdp("metShermaEnclave"),
-    unlocked_dust_cage: UnityPointer<3> = pdp("UnlockedDustCage"),
-    green_prince_location: UnityPointer<3> = pdp("GreenPrinceLocation"),
-    seen_fleatopia_empty: UnityPointer<3> = pdp("SeenFleatopiaEmpty"),
-    flea_games_started: UnityPointer<3> = pdp("FleaGamesStarted"),
-    flea_games_ended: UnityPointer<3> = pdp("FleaGamesEnded"),
-    has_charge_slash: UnityPointer<3> = pdp("hasChargeSlash"),
-    has_double_jump: UnityPointer<3> = pdp("hasDoubleJump"),
-    has_super_jump: UnityPointer<3> = pdp("hasSuperJump"),
-    has_fast_travel_teleport: UnityPointer<3> = pdp("UnlockedFastTravelTeleport"),
-    has_needolin_memory_powerup: UnityPointer<3> = pdp("hasNeedolinMemoryPowerup"),
-    completed_cog_10_abyss_battle: UnityPointer<3> = pdp("completedCog10_abyssBattle"),
-    encountered_flower_queen: UnityPointer<3> = pdp("encounteredFlowerQueen"),
-    defeated_flower_queen: UnityPointer<3> = pdp("defeatedFlowerQueen"),
-    collected_heart_flower: UnityPointer<3> = pdp("CollectedHeartFlower"),
-    encountered_coral_king: UnityPointer<3> = pdp("encounteredCoralKing"),
-    defeated_coral_king: UnityPointer<3> = pdp("defeatedCoralKing"),
-    collected_heart_coral: UnityPointer<3> = pdp("CollectedHeartCoral"),
-    defeated_ant_queen: UnityPointer<3> = pdp("defeatedAntQueen"),
-    collected_heart_hunter: UnityPointer<3> = pdp("CollectedHeartHunter"),
-    encountered_clover_dancers: UnityPointer<3> = pdp("encounteredCloverDancers"),
-    defeated_clover_dancers: UnityPointer<3> = pdp("defeatedCloverDancers"),
-    collected_heart_clover: UnityPointer<3> = pdp("CollectedHeartClover"),
-    completed_red_memory: UnityPointer<3> = pdp("CompletedRedMemory"),
-    belltown_greeter_house_full_dlg: UnityPointer<3> = pdp("BelltownGreeterHouseFullDlg"),
-    orbs_02c: UnityPointer<3> = pdp("memoryOrbs_Clover_02c_A"),
-    orbs_03: UnityPointer<3> = pdp("memoryOrbs_Clover_03_B"),
-    orbs_06: UnityPointer<3> = pdp("memoryOrbs_Clover_06_A"),
-    orbs_11: UnityPointer<3> = pdp("memoryOrbs_Clover_11"),
-    orbs_16_b: UnityPointer<3> = pdp("memoryOrbs_Clover_16_B"),
-    orbs_16_c: UnityPointer<3> = pdp("memoryOrbs_Clover_16_C"),
-    orbs_21: UnityPointer<3> = pdp("memoryOrbs_Clover_21"),
-    orbs_18_a: UnityPointer<3> = pdp("memoryOrbs_Clover_18_A"),
-    orbs_18_b: UnityPointer<3> = pdp("memoryOrbs_Clover_18_B"),
-    orbs_18_c: UnityPointer<3> = pdp("memoryOrbs_Clover_18_C"),
-    orbs_18_d: UnityPointer<3> = pdp("memoryOrbs_Clover_18_D"),
-    orbs_18_e: UnityPointer<3> = pdp("memoryOrbs_Clover_18_E"),
-    orbs_19: UnityPointer<3> = pdp("memoryOrbs_Clover_19"),
-    defeated_white_cloverstag: UnityPointer<3> = pdp("defeatedWhiteCloverstag"),
-    summoned_lake_orbs: UnityPointer<3> = pdp("summonedLakeOrbs"),
-    defeated_dock_foremen: UnityPointer<3> = pdp("defeatedDockForemen"),
-    defeated_swamp_shaman: UnityPointer<3> = pdp("DefeatedSwampShaman"),
-    defeated_bone_flyer_giant: UnityPointer<3> = pdp("defeatedBoneFlyerGiant"),
-    defeated_roach_keeper_chef: UnityPointer<3> = pdp("defeatedRoachkeeperChef"),
-    defeated_brood_mother: UnityPointer<3> = pdp("defeatedBroodMother"),
-    defeated_bone_flyer_giant_golem_scene: UnityPointer<3> = pdp("defeatedBoneFlyerGiantGolemScene"),
-    caravan_troupe_location: UnityPointer<3> = pdp("CaravanTroupeLocation"),
-    belltown_relic_dealer_gave_relic: UnityPointer<3> = pdp("BelltownRelicDealerGaveRelic"),
-    collected_ward_key: UnityPointer<3> = pdp("collectedWardKey"),
-    collected_ward_boss_key: UnityPointer<3> = pdp("collectedWardBossKey"),
-    ward_boss_encountered: UnityPointer<3> = pdp("wardBossEncountered"),
-    ward_boss_defeated: UnityPointer<3> = pdp("wardBossDefeated"),
-    met_gourmand_servant: UnityPointer<3> = pdp("MetGourmandServant"),
-    gourmand_given_meat: UnityPointer<3> = pdp("GourmandGivenMeat"),
-    got_gourmand_reward: UnityPointer<3> = pdp("GotGourmandReward"),
-    belltown_greeter_met_time_passed: UnityPointer<3> = pdp("BelltownGreeterMetTimePassed"),
-    bell_shrine_enclave: UnityPointer<3> = pdp("bellShrineEnclave"),
-    skull_king_defeated: UnityPointer<3> = pdp("skullKingDefeated"),
-    sherma_healer_active: UnityPointer<3> = pdp("shermaHealerActive"),
-    city_merchant_saved: UnityPointer<3> = pdp("cityMerchantSaved"),
-    enclave_merchant_saved: UnityPointer<3> = pdp("enclaveMerchantSaved"),
-    caretaker_offered_snare_quest: UnityPointer<3> = pdp("CaretakerOfferedSnareQuest"),
-    soul_snare_ready: UnityPointer<3> = pdp("soulSnareReady"),
-    defeated_seth: UnityPointer<3> = pdp("defeatedSeth"),
-    completed_abyss_ascent: UnityPointer<3> = pdp("completedAbyssAscent"),
-    defeated_crawfather: UnityPointer<3> = pdp("defeatedCrowCourt"),
-    ballow_moved_to_diving_bell: UnityPointer<3> = pdp("BallowMovedToDivingBell"),
-    black_thread_world: UnityPointer<3> = pdp("blackThreadWorld"),
-    defeated_coral_drillers: UnityPointer<3> = pdp("defeatedCoralDrillers"),
-    defeated_zap_core_enemy: UnityPointer<3> = pdp("defeatedZapCoreEnemy"),
-    defeated_coral_driller_solo: UnityPointer<3> = pdp("defeatedCoralDrillerSolo"),
-    defeated_grey_warrior: UnityPointer<3> = pdp("defeatedGreyWarrior"),
-    defeated_lost_garmond: UnityPointer<3> = pdp("garmondBlackThreadDefeated"),
-    encountered_plasmified_zango: UnityPointer<3> = pdp("BlueAssistantEnemyEncountered"),
-    encountered_lost_lace: UnityPointer<3> = pdp("EncounteredLostLace"),
-    completion_percentage: UnityPointer<3> = pdp("completionPercentage"),
-
-    // asr currently struggles to resolve these by name consistently
-    // _instance.playerData.Tools.RuntimeData._version
-    tools_version: UnityPointer<5> = UnityPointer::new("GameManager", 0, &["_instance", "playerData", "Tools", "0x18", "0x4c"]),
-    // _instance.playerData.Tools.RuntimeData._entries
-    tools_entries: UnityPointer<5> = UnityPointer::new("GameManager", 0, &["_instance", "playerData", "Tools", "0x18", "0x18"]),
+    disable_pause: UnityPointer<3> as bool = pdp("disablePause"),
+    is_inventory_open: UnityPointer<3> as bool = pdp("isInventoryOpen"),
+    travelling: UnityPointer<3> as bool = pdp("travelling"),
+    is_teleporting: UnityPointer<3> as bool = pdp("IsTeleporting"),
+    health: UnityPointer<3> as i32 = pdp("health"),
+    max_health_base: UnityPointer<3> as i32 = pdp("maxHealthBase"),
+    heart_pieces: UnityPointer<3> as i32 = pdp("heartPieces"),
+    at_bench: UnityPointer<3> as bool = pdp("atBench"),
+    respawn_scene: UnityPointer<3> as Address64 = pdp("respawnScene"),
+    geo: UnityPointer<3> as i32 = pdp("geo"),
+    shell_shards: UnityPointer<3> as i32 = pdp("ShellShards"),
+    play_time: UnityPointer<3> as f32 = pdp("playTime"),
+    defeated_moss_mother: UnityPointer<3> as bool = pdp("defeatedMossMother"),
+    has_needle_throw: UnityPointer<3> as bool = pdp("hasNeedleThrow"),
+    has_parry: UnityPointer<3> as bool = pdp("hasParry"),
+    has_thread_sphere: UnityPointer<3> as bool = pdp("hasThreadSphere"),
+    has_silk_charge: UnityPointer<3> as bool = pdp("hasSilkCharge"),
+    has_silk_bomb: UnityPointer<3> as bool = pdp("hasSilkBomb"),
+    has_silk_boss_needle: UnityPointer<3> as bool = pdp("hasSilkBossNeedle"),
+    has_bonebottom_simple_key: UnityPointer<3> as bool = pdp("PurchasedBonebottomFaithToken"),
+    collected_dust_cage_key: UnityPointer<3> as bool = pdp("CollectedDustCageKey"),
+    merchant_enclave_simple_key: UnityPointer<3> as bool = pdp("MerchantEnclaveSimpleKey"),
+    defeated_bell_beast: UnityPointer<3> as bool = pdp("defeatedBellBeast"),
+    bell_shrine_bone_forest: UnityPointer<3> as bool = pdp("bellShrineBoneForest"),
+    has_dash: UnityPointer<3> as bool = pdp("hasDash"),
+    defeated_lace1: UnityPointer<3> as bool = pdp("defeatedLace1"),
+    bell_shrine_wilds: UnityPointer<3> as bool = pdp("bellShrineWilds"),
+    has_brolly: UnityPointer<3> as bool = pdp("hasBrolly"),
+    defeated_song_golem: UnityPointer<3> as bool = pdp("defeatedSongGolem"),
+    bell_shrine_greymoor: UnityPointer<3> as bool = pdp("bellShrineGreymoor"),
+    defeated_vampire_gnat_boss: UnityPointer<3> as bool = pdp("defeatedVampireGnatBoss"),
+    encountered_sister_splinter: UnityPointer<3> as bool = pdp("encounteredSplinterQueen"),
+    defeated_sister_splinter: UnityPointer<3> as bool = pdp("defeatedSplinterQueen"),
+    has_wall_jump: UnityPointer<3> as bool = pdp("hasWalljump"),
+    encountered_spinner: UnityPointer<3> as bool = pdp("encounteredSpinner"),
+    spinner_defeated: UnityPointer<3> as bool = pdp("spinnerDefeated"),
+    bell_shrine_bellhart: UnityPointer<3> as bool = pdp("bellShrineBellhart"),
+    bell_shrine_shellwood: UnityPointer<3> as bool = pdp("bellShrineShellwood"),
+    encountered_last_judge: UnityPointer<3> as bool = pdp("encounteredLastJudge"),
+    defeated_last_judge: UnityPointer<3> as bool = pdp("defeatedLastJudge"),
+    defeated_phantom: UnityPointer<3> as bool = pdp("defeatedPhantom"),
+    act2_started: UnityPointer<3> as bool = pdp("act2Started"),
+    encountered_cogwork_dancers: UnityPointer<3> as bool = pdp("encounteredCogworkDancers"),
+    defeated_cogwork_dancers: UnityPointer<3> as bool = pdp("defeatedCogworkDancers"),
+    woke_song_chevalier: UnityPointer<3> as bool = pdp("wokeSongChevalier"),
+    encountered_song_chevalier_boss: UnityPointer<3> as bool = pdp("encounteredSongChevalierBoss"),
+    defeated_song_chevalier_boss: UnityPointer<3> as bool = pdp("defeatedSongChevalierBoss"),
+    completed_library_entry_battle: UnityPointer<3> as bool = pdp("completedLibraryEntryBattle"),
+    encountered_trobbio: UnityPointer<3> as bool = pdp("encounteredTrobbio"),
+    defeated_trobbio: UnityPointer<3> as bool = pdp("defeatedTrobbio"),
+    encountered_tormented_trobbio: UnityPointer<3> as bool = pdp("encounteredTormentedTrobbio"),
+    defeated_tormented_trobbio: UnityPointer<3> as bool = pdp("defeatedTormentedTrobbio"),
+    has_harpoon_dash: UnityPointer<3> as bool = pdp("hasHarpoonDash"),
+    hang04_battle: UnityPointer<3> as bool = pdp("hang04Battle"),
+    defeated_lace_tower: UnityPointer<3> as bool = pdp("defeatedLaceTower"),
+    has_melody_librarian: UnityPointer<3> as bool = pdp("HasMelodyLibrarian"),
+    has_melody_conductor: UnityPointer<3> as bool = pdp("HasMelodyConductor"),
+    has_melody_architect: UnityPointer<3> as bool = pdp("HasMelodyArchitect"),
+    unlocked_melody_lift: UnityPointer<3> as bool = pdp("UnlockedMelodyLift"),
+    nail_upgrades: UnityPointer<3> as i32 = pdp("nailUpgrades"),
+    silk: UnityPointer<3> as i32 = pdp("silk"),
+    silk_max: UnityPointer<3> as i32 = pdp("silkMax"),
+    silk_spool_parts: UnityPointer<3> as i32 = pdp("silkSpoolParts"),
+    completed_memory_reaper: UnityPointer<3> as bool = pdp("completedMemory_reaper"),
+    completed_memory_wanderer: UnityPointer<3> as bool = pdp("completedMemory_wanderer"),
+    completed_memory_beast: UnityPointer<3> as bool = pdp("completedMemory_beast"),
+    completed_memory_toolmaster: UnityPointer<3> as bool = pdp("completedMemory_toolmaster"),
+    completed_memory_witch: UnityPointer<3> as bool = pdp("completedMemory_witch"),
+    gained_curse: UnityPointer<3> as bool = pdp("gainedCurse"),
+    belltown_doctor_cured_curse: UnityPointer<3> as bool = pdp("BelltownDoctorCuredCurse"),
+    belltown_doctor_convo: UnityPointer<3> as i32 = pdp("BelltownDoctorConvo"),
+    completed_memory_shaman: UnityPointer<3> as bool = pdp("completedMemory_shaman"),
+    has_bound_crest_upgrader: UnityPointer<3> as bool = pdp("HasBoundCrestUpgrader"),
+    current_crest_id: UnityPointer<3> as Address64 = pdp("CurrentCrestID"),
+    unlocked_extra_blue_slot: UnityPointer<3> as bool = pdp("UnlockedExtraBlueSlot"),
+    unlocked_extra_yellow_slot: UnityPointer<3> as bool = pdp("UnlockedExtraYellowSlot"),
+    tool_pouch_upgrades: UnityPointer<3> as i32 = pdp("ToolPouchUpgrades"),
+    tool_kit_upgrades: UnityPointer<3> as i32 = pdp("ToolKitUpgrades"),
+
+    defeated_wisp_pyre_effigy: UnityPointer<3> as bool =  pdp("defeatedWispPyreEffigy"),
+    has_slab_key_a: UnityPointer<3> as bool =  pdp("HasSlabKeyA"),
+    has_slab_key_b: UnityPointer<3> as bool =  pdp("HasSlabKeyB"),
+    has_slab_key_c: UnityPointer<3> as bool =  pdp("HasSlabKeyC"),
+    encountered_first_weaver: UnityPointer<3> as bool =  pdp("encounteredFirstWeaver"),
+    defeated_first_weaver: UnityPointer<3> as bool = pdp("defeatedFirstWeaver"),
+    encountered_ant_trapper: UnityPointer<3> as bool = pdp("encounteredAntTrapper"),
+    defeated_ant_trapper: UnityPointer<3> as bool = pdp("defeatedAntTrapper"),
+
+    savedflea_ant_03: UnityPointer<3> as bool = pdp("SavedFlea_Ant_03"),
+    savedflea_belltown_04: UnityPointer<3> as bool = pdp("SavedFlea_Belltown_04"),
+    savedflea_bone_06: UnityPointer<3> as bool = pdp("SavedFlea_Bone_06"),
+    savedflea_bone_east_05: UnityPointer<3> as bool = pdp("SavedFlea_Bone_East_05"),
+    savedflea_bone_east_10_church: UnityPointer<3> as bool = pdp("SavedFlea_Bone_East_10_Church"),
+    savedflea_bone_east_17b: UnityPointer<3> as bool = pdp("SavedFlea_Bone_East_17b"),
+    savedflea_coral_24: UnityPointer<3> as bool = pdp("SavedFlea_Coral_24"),
+    savedflea_coral_35: UnityPointer<3> as bool = pdp("SavedFlea_Coral_35"),
+    savedflea_crawl_06: UnityPointer<3> as bool = pdp("SavedFlea_Crawl_06"),
+    savedflea_dock_03d: UnityPointer<3> as bool = pdp("SavedFlea_Dock_03d"),
+    savedflea_dock_16: UnityPointer<3> as bool = pdp("SavedFlea_Dock_16"),
+    savedflea_dust_09: UnityPointer<3> as bool = pdp("SavedFlea_Dust_09"),
+    savedflea_dust_12: UnityPointer<3> as bool = pdp("SavedFlea_Dust_12"),
+    savedflea_greymoor_06: UnityPointer<3> as bool = pdp("SavedFlea_Greymoor_06"),
+    savedflea_greymoor_15b: UnityPointer<3> as bool = pdp("SavedFlea_Greymoor_15b"),
+    savedflea_library_01: UnityPointer<3> as bool = pdp("SavedFlea_Library_01"),
+    savedflea_library_09: UnityPointer<3> as bool = pdp("SavedFlea_Library_09"),
+    savedflea_peak_05c: UnityPointer<3> as bool = pdp("SavedFlea_Peak_05c"),
+    savedflea_shadow_10: UnityPointer<3> as bool = pdp("SavedFlea_Shadow_10"),
+    savedflea_shadow_28: UnityPointer<3> as bool = pdp("SavedFlea_Shadow_28"),
+    savedflea_shellwood_03: UnityPointer<3> as bool = pdp("SavedFlea_Shellwood_03"),
+    savedflea_slab_06: UnityPointer<3> as bool = pdp("SavedFlea_Slab_06"),
+    savedflea_slab_cell: UnityPointer<3> as bool = pdp("SavedFlea_Slab_Cell"),
+    savedflea_song_11: UnityPointer<3> as bool = pdp("SavedFlea_Song_11"),
+    savedflea_song_14: UnityPointer<3> as bool = pdp("SavedFlea_Song_14"),
+    savedflea_under_21: UnityPointer<3> as bool = pdp("SavedFlea_Under_21"),
+    savedflea_under_23: UnityPointer<3> as bool = pdp("SavedFlea_Under_23"),
+    tamed_giant_flea: UnityPointer<3> as bool = pdp("tamedGiantFlea"),
+    met_troupe_hunter_wild: UnityPointer<3> as bool = pdp("MetTroupeHunterWild"),
+    caravan_lech_saved: UnityPointer<3> as bool = pdp("CaravanLechSaved"),
+
+    unlocked_aqueduct_station: UnityPointer<3> as bool = pdp("UnlockedAqueductStation"),
+    unlocked_belltown_station: UnityPointer<3> as bool = pdp("UnlockedBelltownStation"),
+    unlocked_boneforest_east_station: UnityPointer<3> as bool = pdp("UnlockedBoneforestEastStation"),
+    unlocked_city_station: UnityPointer<3> as bool = pdp("UnlockedCityStation"),
+    unlocked_coral_tower_station: UnityPointer<3> as bool = pdp("UnlockedCoralTowerStation"),
+    unlocked_docks_station: UnityPointer<3> as bool = pdp("UnlockedDocksStation"),
+    unlocked_greymoor_station: UnityPointer<3> as bool = pdp("UnlockedGreymoorStation"),
+    unlocked_peak_station: UnityPointer<3> as bool = pdp("UnlockedPeakStation"),
+    unlocked_shadow_station: UnityPointer<3> as bool = pdp("UnlockedShadowStation"),
+    unlocked_shellwood_station: UnityPointer<3> as bool = pdp("UnlockedShellwoodStation"),
+
+    unlocked_song_tube: UnityPointer<3> as bool = pdp("UnlockedSongTube"),
+    unlocked_under_tube: UnityPointer<3> as bool = pdp("UnlockedUnderTube"),
+    unlocked_city_bellway_tube: UnityPointer<3> as bool = pdp("UnlockedCityBellwayTube"),
+    unlocked_hang_tube: UnityPointer<3> as bool = pdp("UnlockedHangTube"),
+    unlocked_enclave_tube: UnityPointer<3> as bool = pdp("UnlockedEnclaveTube"),
+    unlocked_arborium_tube: UnityPointer<3> as bool = pdp("UnlockedArboriumTube"),
+
+    seen_mapper_bonetown: UnityPointer<3> as bool = pdp("SeenMapperBonetown"),
+    seen_mapper_bone_forest: UnityPointer<3> as bool = pdp("SeenMapperBoneForest"),
+    seen_mapper_docks: UnityPointer<3> as bool = pdp("SeenMapperDocks"),
+    seen_mapper_wilds: UnityPointer<3> as bool = pdp("SeenMapperWilds"),
+    seen_mapper_crawl: UnityPointer<3> as bool = pdp("SeenMapperCrawl"),
+    seen_mapper_greymoor: UnityPointer<3> as bool = pdp("SeenMapperGreymoor"),
+    seen_mapper_bellhart: UnityPointer<3> as bool = pdp("SeenMapperBellhart"),
+    seen_mapper_shellwood: UnityPointer<3> as bool = pdp("SeenMapperShellwood"),
+    seen_mapper_hunters_nest: UnityPointer<3> as bool = pdp("SeenMapperHuntersNest"),
+    seen_mapper_judge_steps: UnityPointer<3> as bool = pdp("SeenMapperJudgeSteps"),
+    seen_mapper_dustpens: UnityPointer<3> as bool = pdp("SeenMapperDustpens"),
+    seen_mapper_peak: UnityPointer<3> as bool = pdp("SeenMapperPeak"),
+    seen_mapper_shadow: UnityPointer<3> as bool = pdp("SeenMapperShadow"),
+    seen_mapper_coral_caverns: UnityPointer<3> as bool = pdp("SeenMapperCoralCaverns"),
+
+    has_moss_grotto_map: UnityPointer<3> as bool = pdp("HasMossGrottoMap"),
+    has_boneforest_map: UnityPointer<3> as bool = pdp("HasBoneforestMap"),
+    has_docks_map: UnityPointer<3> as bool = pdp("HasDocksMap"),
+    has_wilds_map: UnityPointer<3> as bool = pdp("HasWildsMap"),
+    has_crawl_map: UnityPointer<3> as bool = pdp("HasCrawlMap"),
+    has_greymoor_map: UnityPointer<3> as bool = pdp("HasGreymoorMap"),
+    has_bellhart_map: UnityPointer<3> as bool = pdp("HasBellhartMap"),
+    has_shellwood_map: UnityPointer<3> as bool = pdp("HasShellwoodMap"),
+    has_hunters_nest_map: UnityPointer<3> as bool = pdp("HasHuntersNestMap"),
+    has_judge_steps_map: UnityPointer<3> as bool = pdp("HasJudgeStepsMap"),
+    has_dustpens_map: UnityPointer<3> as bool = pdp("HasDustpensMap"),
+    has_peak_map: UnityPointer<3> as bool = pdp("HasPeakMap"),
+    has_swamp_map: UnityPointer<3> as bool = pdp("HasSwampMap"),
+    has_coral_map: UnityPointer<3> as bool = pdp("HasCoralMap"),
+
+    met_city_merchant_enclave: UnityPointer<3> as bool = pdp("MetCityMerchantEnclave"),
+    met_sherma_enclave: UnityPointer<3> as bool = pdp("metShermaEnclave"),
+    unlocked_dust_cage: UnityPointer<3> as bool = pdp("UnlockedDustCage"),
+    green_prince_location: UnityPointer<3> as i32 = pdp("GreenPrinceLocation"),
+    seen_fleatopia_empty: UnityPointer<3> as bool = pdp("SeenFleatopiaEmpty"),
+    flea_games_started: UnityPointer<3> as bool = pdp("FleaGamesStarted"),
+    flea_games_ended: UnityPointer<3> as bool = pdp("FleaGamesEnded"),
+    has_charge_slash: UnityPointer<3> as bool = pdp("hasChargeSlash"),
+    has_double_jump: UnityPointer<3> as bool = pdp("hasDoubleJump"),
+    has_super_jump: UnityPointer<3> as bool = pdp("hasSuperJump"),
+    has_fast_travel_teleport: UnityPointer<3> as bool = pdp("UnlockedFastTravelTeleport"),
+    has_needolin_memory_powerup: UnityPointer<3> as bool = pdp("hasNeedolinMemoryPowerup"),
+    completed_cog_10_abyss_battle: UnityPointer<3> as bool = pdp("completedCog10_abyssBattle"),
+    encountered_flower_queen: UnityPointer<3> as bool = pdp("encounteredFlowerQueen"),
+    defeated_flower_queen: UnityPointer<3> as bool = pdp("defeatedFlowerQueen"),
+    collected_heart_flower: UnityPointer<3> as bool = pdp("CollectedHeartFlower"),
+    encountered_coral_king: UnityPointer<3> as bool = pdp("encounteredCoralKing"),
+    defeated_coral_king: UnityPointer<3> as bool = pdp("defeatedCoralKing"),
+    collected_heart_coral: UnityPointer<3> as bool = pdp("CollectedHeartCoral"),
+    defeated_ant_queen: UnityPointer<3> as bool = pdp("defeatedAntQueen"),
+    collected_heart_hunter: UnityPointer<3> as bool = pdp("CollectedHeartHunter"),
+    encountered_clover_dancers: UnityPointer<3> as bool = pdp("encounteredCloverDancers"),
+    defeated_clover_dancers: UnityPointer<3> as bool = pdp("defeatedCloverDancers"),
+    collected_heart_clover: UnityPointer<3> as bool = pdp("CollectedHeartClover"),
+    completed_red_memory: UnityPointer<3> as bool = pdp("CompletedRedMemory"),
+    belltown_greeter_house_full_dlg: UnityPointer<3> as bool = pdp("BelltownGreeterHouseFullDlg"),
+    orbs_02c: UnityPointer<3> as bool = pdp("memoryOrbs_Clover_02c_A"),
+    orbs_03: UnityPointer<3> as bool = pdp("memoryOrbs_Clover_03_B"),
+    orbs_06: UnityPointer<3> as bool = pdp("memoryOrbs_Clover_06_A"),
+    orbs_11: UnityPointer<3> as bool = pdp("memoryOrbs_Clover_11"),
+    orbs_16_b: UnityPointer<3> as bool = pdp("memoryOrbs_Clover_16_B"),
+    orbs_16_c: UnityPointer<3> as bool = pdp("memoryOrbs_Clover_16_C"),
+    orbs_21: UnityPointer<3> as bool = pdp("memoryOrbs_Clover_21"),
+    orbs_18_a: UnityPointer<3> as u64 = pdp("memoryOrbs_Clover_18_A"),
+    orbs_18_b: UnityPointer<3> as u64 = pdp("memoryOrbs_Clover_18_B"),
+    orbs_18_c: UnityPointer<3> as u64 = pdp("memoryOrbs_Clover_18_C"),
+    orbs_18_d: UnityPointer<3> as u64 = pdp("memoryOrbs_Clover_18_D"),
+    orbs_18_e: UnityPointer<3> as u64 = pdp("memoryOrbs_Clover_18_E"),
+    orbs_19: UnityPointer<3> as u64 = pdp("memoryOrbs_Clover_19"),
+    defeated_white_cloverstag: UnityPointer<3> as bool = pdp("defeatedWhiteCloverstag"),
+    summoned_lake_orbs: UnityPointer<3> as bool = pdp("summonedLakeOrbs"),
+    defeated_dock_foremen: UnityPointer<3> as bool = pdp("defeatedDockForemen"),
+    defeated_swamp_shaman: UnityPointer<3> as bool = pdp("DefeatedSwampShaman"),
+    defeated_bone_flyer_giant: UnityPointer<3> as bool = pdp("defeatedBoneFlyerGiant"),
+    defeated_roach_keeper_chef: UnityPointer<3> as bool = pdp("defeatedRoachkeeperChef"),
+    defeated_brood_mother: UnityPointer<3> as bool = pdp("defeatedBroodMother"),
+    defeated_bone_flyer_giant_golem_scene: UnityPointer<3> as bool = pdp("defeatedBoneFlyerGiantGolemScene"),
+    caravan_troupe_location: UnityPointer<3> as i32 = pdp("CaravanTroupeLocation"),
+    belltown_relic_dealer_gave_relic: UnityPointer<3> as bool = pdp("BelltownRelicDealerGaveRelic"),
+    collected_ward_key: UnityPointer<3> as bool = pdp("collectedWardKey"),
+    collected_ward_boss_key: UnityPointer<3> as bool = pdp("collectedWardBossKey"),
+    ward_boss_encountered: UnityPointer<3> as bool = pdp("wardBossEncountered"),
+    ward_boss_defeated: UnityPointer<3> as bool = pdp("wardBossDefeated"),
+    met_gourmand_servant: UnityPointer<3> as bool = pdp("MetGourmandServant"),
+    gourmand_given_meat: UnityPointer<3> as bool = pdp("GourmandGivenMeat"),
+    got_gourmand_reward: UnityPointer<3> as bool = pdp("GotGourmandReward"),
+    belltown_greeter_met_time_passed: UnityPointer<3> as bool = pdp("BelltownGreeterMetTimePassed"),
+    bell_shrine_enclave: UnityPointer<3> as bool = pdp("bellShrineEnclave"),
+    skull_king_defeated: UnityPointer<3> as bool = pdp("skullKingDefeated"),
+    sherma_healer_active: UnityPointer<3> as bool = pdp("shermaHealerActive"),
+    city_merchant_saved: UnityPointer<3> as bool = pdp("cityMerchantSaved"),
+    enclave_merchant_saved: UnityPointer<3> as bool = pdp("enclaveMerchantSaved"),
+    caretaker_offered_snare_quest: UnityPointer<3> as bool = pdp("CaretakerOfferedSnareQuest"),
+    soul_snare_ready: UnityPointer<3> as bool = pdp("soulSnareReady"),
+    defeated_seth: UnityPointer<3> as bool = pdp("defeatedSeth"),
+    completed_abyss_ascent: UnityPointer<3> as bool = pdp("completedAbyssAscent"),
+    defeated_crawfather: UnityPointer<3> as bool = pdp("defeatedCrowCourt"),
+    ballow_moved_to_diving_bell: UnityPointer<3> as bool = pdp("BallowMovedToDivingBell"),
+    black_thread_world: UnityPointer<3> as bool = pdp("blackThreadWorld"),
+    defeated_coral_drillers: UnityPointer<3> as bool = pdp("defeatedCoralDrillers"),
+    defeated_zap_core_enemy: UnityPointer<3> as bool = pdp("defeatedZapCoreEnemy"),
+    defeated_coral_driller_solo: UnityPointer<3> as bool = pdp("defeatedCoralDrillerSolo"),
+    defeated_grey_warrior: UnityPointer<3> as bool = pdp("defeatedGreyWarrior"),
+    defeated_lost_garmond: UnityPointer<3> as bool = pdp("garmondBlackThreadDefeated"),
+    encountered_plasmified_zango: UnityPointer<3> as bool = pdp("BlueAssistantEnemyEncountered"),
+    encountered_lost_lace: UnityPointer<3> as bool = pdp("EncounteredLostLace"),
+    completion_percentage: UnityPointer<3> as f32 = pdp("completionPercentage"),
+
+    // asr doesn't always resolve these by name through the generic base class and Dictionary,
+    // so they fall back to the offsets of RuntimeData and the Dictionary fields
+    tools_version: UnityPointer<5> as i32 = UnityPointer::new("GameManager", 0, &["_instance", "playerData", "Tools", "RuntimeData", "_version"])
+        => UnityPointer::new("GameManager", 0, &["_instance", "playerData", "Tools", "0x18", "0x4c"]),
+    tools_entries: UnityPointer<5> as Address64 = UnityPointer::new("GameManager", 0, &["_instance", "playerData", "Tools", "RuntimeData", "_entries"])
+        => UnityPointer::new("GameManager", 0, &["_instance", "playerData", "Tools", "0x18", "0x18"]),
+    quest_version: UnityPointer<5> as i32 = UnityPointer::new("GameManager", 0, &["_instance", "playerData", "QuestCompletionData", "RuntimeData", "_version"])
+        => UnityPointer::new("GameManager", 0, &["_instance", "playerData", "QuestCompletionData", "0x18", "0x4c"]),
+    quest_entries: UnityPointer<5> as Address64 = UnityPointer::new("GameManager", 0, &["_instance", "playerData", "QuestCompletionData", "RuntimeData", "_entries"])
+        => UnityPointer::new("GameManager", 0, &["_instance", "playerData", "QuestCompletionData", "0x18", "0x18"]),
+    relics_version: UnityPointer<5> as i32 = UnityPointer::new("GameManager", 0, &["_instance", "playerData", "Relics", "RuntimeData", "_version"])
+        => UnityPointer::new("GameManager", 0, &["_instance", "playerData", "Relics", "0x18", "0x4c"]),
+    relics_entries: UnityPointer<5> as Address64 = UnityPointer::new("GameManager", 0, &["_instance", "playerData", "Relics", "RuntimeData", "_entries"])
+        => UnityPointer::new("GameManager", 0, &["_instance", "playerData", "Relics", "0x18", "0x18"]),
 });
 
 // --------------------------------------------------------
@@ -498,11 +979,27 @@ pub struct Memory<'a> {
     pub module: Box<mono::Module>,
     pub image: mono::Image,
     pub string_list_offsets: Box<StringListOffsets>,
+    pub dictionary_offsets: Box<DictionaryOffsets>,
+    pub scene_data_offsets: Box<SceneDataOffsets>,
+    /// Where each SceneData item was found, by the name of the collection's pointer,
+    /// the scene, and the id
+    scene_items: RefCell<BTreeMap<(String, String, String), SceneItem>>,
+    /// Where each quest's value is in the QuestCompletionData entries, by quest id
+    quest_values: RefCell<BTreeMap<String, QuestValue>>,
+    /// The build of the game, as read by [Memory::wait_attach],
+    /// or by [Memory::read_build_version] when a save file was loaded then
+    pub version: Option<GameVersion>,
+    /// `PlayerData.version`, for reading the build of the game
+    player_data_version: FieldPointer<3>,
+    /// `PlayerData.profileID`, for telling whether a save file is loaded
+    player_data_profile_id: FieldPointer<3>,
+    /// Whether [Memory::find_scene_item] has logged that the [SceneDataOffsets] don't match
+    scene_data_layout_mismatch: Cell<bool>,
 }
 
 impl Memory<'_> {
     pub async fn wait_attach<'a>(process: &'a Process) -> Memory<'a> {
-        asr::print_message("Memory wait_attach: Module wait_attach...");
+        runtime::print_message("Memory wait_attach: Module wait_attach...");
         next_tick().await;
         let mut found_module = false;
         let mut needed_retry = false;
@@ -510,43 +1007,127 @@ impl Memory<'_> {
             let module = mono::Module::wait_attach(process, mono::Version::V3).await;
             if !found_module {
                 found_module = true;
-                asr::print_message("Memory wait_attach: module get_default_image...");
+                runtime::print_message("Memory wait_attach: module get_default_image...");
                 next_tick().await;
             }
             for _ in 0..0x10 {
                 if let Some(image) = module.get_default_image(process) {
-                    asr::print_message(&format!(
+                    runtime::print_message(&format!(
                         "Memory wait_attach: got module and image, pointer_size = {:?}, Mono version = {:?}",
                         module.get_pointer_size(),
                         module.get_version(),
                     ));
                     next_tick().await;
-                    return Memory {
+                    let mut mem = Memory {
                         process,
                         module: Box::new(module),
                         image,
                         string_list_offsets: Box::new(StringListOffsets::new()),
+                        dictionary_offsets: Box::new(DictionaryOffsets::new()),
+                        scene_data_offsets: Box::new(SceneDataOffsets::new()),
+                        scene_items: RefCell::new(BTreeMap::new()),
+                        quest_values: RefCell::new(BTreeMap::new()),
+                        version: None,
+                        player_data_version: FieldPointer::new(
+                            "version",
+                            mem::size_of::<Address64>(),
+                            pdp("version"),
+                        ),
+                        player_data_profile_id: FieldPointer::new(
+                            "profileID",
+                            mem::size_of::<i32>(),
+                            pdp("profileID"),
+                        ),
+                        scene_data_layout_mismatch: Cell::new(false),
                     };
+                    match mem.wait_attach_version().await {
+                        Some(version) => {
+                            runtime::print_message(&format!(
+                                "Memory wait_attach: game version = {}",
+                                version.0
+                            ));
+                            mem.set_version(version);
+                        }
+                        None => runtime::print_message(
+                            "Memory wait_attach: save file loaded, reading the game version on the main menu",
+                        ),
+                    }
+                    return mem;
                 }
                 next_tick().await;
             }
             if !needed_retry {
                 needed_retry = true;
-                asr::print_message("Memory wait_attach: retry...");
+                runtime::print_message("Memory wait_attach: retry...");
                 next_tick().await;
             }
         }
     }
 
-    pub fn deref<T: CheckedBitPattern, const CAP: usize>(
-        &self,
-        p: &UnityPointer<CAP>,
-    ) -> Result<T, asr::Error> {
-        p.deref(self.process, &self.module, &self.image)
+    /// Waits for PlayerData to exist, and reads the build of the game that's running
+    /// if no save file has been loaded yet, as when attaching while the game starts.
+    ///
+    /// This assumes that `PlayerData.profileID` is 0 until a save file is loaded,
+    /// since save slots are numbered from 1.
+    /// With a save file loaded, `PlayerData.version` is the build that wrote it,
+    /// so this produces None, leaving [Memory::read_build_version] to read it on the main menu.
+    async fn wait_attach_version(&self) -> Option<GameVersion> {
+        let mem: &dyn GameMemory = self;
+        let mut logged = false;
+        loop {
+            match mem.deref::<i32>(&self.player_data_profile_id) {
+                Ok(0) => {
+                    let v = self.read_string(&self.player_data_version)?;
+                    return (!v.is_empty()).then_some(GameVersion(v));
+                }
+                Ok(_) => return None,
+                Err(_) => {
+                    if !logged {
+                        logged = true;
+                        runtime::print_message("Memory wait_attach: waiting for PlayerData...");
+                    }
+                    next_tick().await;
+                }
+            }
+        }
+    }
+
+    /// Reads the build of the game that's running, if the game is on the main menu.
+    ///
+    /// `PlayerData.version` is saved in the save file, so a save that was just loaded
+    /// has the build that wrote it, until the game saves it again with the build that's running.
+    /// On the main menu, PlayerData is either the one the game sets up before any save is loaded,
+    /// or the one it saved when quitting to the menu, so that's the only time this reads it.
+    pub fn read_build_version(&self, gm: &GameManagerPointers) -> Option<GameVersion> {
+        let mem: &dyn GameMemory = self;
+        let game_state: i32 = mem.deref(&gm.game_state).ok()?;
+        if game_state != GAME_STATE_MAIN_MENU || self.read_string(&gm.scene_name)? != MENU_TITLE {
+            return None;
+        }
+        let v = self.read_string(&self.player_data_version)?;
+        (!v.is_empty()).then_some(GameVersion(v))
     }
 
-    pub fn read_string<const CAP: usize>(&self, p: &UnityPointer<CAP>) -> Option<String> {
-        let a: Address64 = self.deref(p).ok()?;
+    /// Uses the offsets for the `version` of the game from now on,
+    /// for when [Memory::wait_attach] or [Memory::read_build_version] has read it.
+    /// The [GameManagerPointers] and [PlayerDataPointers] for it come from their `for_version`.
+    pub fn set_version(&mut self, version: GameVersion) {
+        let v = Some(&version);
+        *self.string_list_offsets = StringListOffsets::for_version(v);
+        *self.dictionary_offsets = DictionaryOffsets::for_version(v);
+        *self.scene_data_offsets = SceneDataOffsets::for_version(v);
+        self.version = Some(version);
+    }
+
+    fn read_address(&self, p: &dyn NamedPointer) -> Option<Address64> {
+        let a = p
+            .deref_offsets(self.process, &self.module, &self.image)
+            .ok()?;
+        self.process.read(a).ok()
+    }
+
+    /// Reads the UTF-16 contents of the C# string at `a`.
+    fn read_utf16_at(&self, a: Address64) -> Option<Vec<u16>> {
         let n: u32 = self
             .process
             .read(a + self.string_list_offsets.string_len)
@@ -555,24 +1136,389 @@ impl Memory<'_> {
             return None;
         }
         // n < 2048
-        let w: Vec<u16> = self
-            .process
+        self.process
             .read_vec(a + self.string_list_offsets.string_contents, n as usize)
+            .ok()
+    }
+
+    /// Reads the C# string at `a`, such as a key from [Memory::read_dictionary].
+    pub fn read_string_at(&self, a: Address64) -> Option<String> {
+        String::from_utf16(&self.read_utf16_at(a)?).ok()
+    }
+
+    /// The length of the array at `a`, if it isn't too long to be real.
+    fn read_array_len(&self, a: Address64) -> Option<u64> {
+        let len: i32 = self
+            .process
+            .read(a + self.string_list_offsets.array_len)
+            .ok()?;
+        (0..MAX_COLLECTION_LEN).contains(&len).then_some(len as u64)
+    }
+
+    /// Reads `n` elements of `size` bytes each, starting at the first element of the array at `a`.
+    fn read_array_elements<T: CheckedBitPattern>(
+        &self,
+        a: Address64,
+        n: u64,
+        size: u64,
+        offset: u64,
+    ) -> Option<Vec<T>> {
+        let contents = a + self.string_list_offsets.array_contents + offset;
+        let width = mem::size_of::<T>();
+        // The last element only needs its own bytes, not the rest of its entry
+        let total = match n {
+            0 => 0,
+            n => (size * (n - 1)) as usize + width,
+        };
+        let mut bytes = vec![0; total];
+        self.process.read_into_buf(contents, &mut bytes).ok()?;
+        bytes
+            .chunks(size as usize)
+            .map(|c| checked::try_pod_read_unaligned(c.get(..width)?).ok())
+            .collect()
+    }
+
+    /// Reads the Mono array `T[]` at `a`, such as [Address64] for an array of references.
+    pub fn read_array<T: CheckedBitPattern>(&self, a: Address64) -> Option<Vec<T>> {
+        let len = self.read_array_len(a)?;
+        self.read_array_elements(a, len, mem::size_of::<T>() as u64, 0)
+    }
+
+    /// Reads the Mono `List<T>` at `a`, such as [Address64] for a list of references.
+    pub fn read_list<T: CheckedBitPattern>(&self, a: Address64) -> Option<Vec<T>> {
+        let o = &self.string_list_offsets;
+        let items: Address64 = self.process.read(a + o.list_array).ok()?;
+        let size: i32 = self.process.read(a + o.list_size).ok()?;
+        let capacity = self.read_array_len(items)?;
+        if !(0..=capacity as i64).contains(&(size as i64)) {
+            return None;
+        }
+        self.read_array_elements(items, size as u64, mem::size_of::<T>() as u64, 0)
+    }
+
+    /// Reads the Mono `Dictionary<K, V>` at `a`, where `K` is a reference type such as a string,
+    /// as the address of each key along with its value, skipping the entries that are empty.
+    pub fn read_dictionary<V: CheckedBitPattern>(
+        &self,
+        a: Address64,
+    ) -> Option<Vec<(Address64, V)>> {
+        let entries = self
+            .process
+            .read(a + self.dictionary_offsets.dictionary_entries)
             .ok()?;
-        String::from_utf16(&w).ok()
+        self.read_dictionary_entries(entries)
+    }
+
+    /// Like [Memory::read_dictionary], given the `_entries` array of the Dictionary.
+    pub fn read_dictionary_entries<V: CheckedBitPattern>(
+        &self,
+        entries: Address64,
+    ) -> Option<Vec<(Address64, V)>> {
+        let o = &self.dictionary_offsets;
+        let len = self.read_array_len(entries)?;
+        // entries are aligned to the key's 8 bytes
+        let size = (o.entry_value + mem::size_of::<V>() as u64).next_multiple_of(8);
+        let keys: Vec<Address64> = self.read_array_elements(entries, len, size, o.entry_key)?;
+        let values: Vec<V> = self.read_array_elements(entries, len, size, o.entry_value)?;
+        Some(
+            keys.into_iter()
+                .zip(values)
+                .filter(|(k, _)| !k.is_null())
+                .collect(),
+        )
+    }
+
+    /// Whether the C# string at `a` is `utf16`,
+    /// comparing lengths first to skip reading the strings that can't match.
+    fn string_eq_utf16(&self, a: Address64, utf16: &[u16]) -> bool {
+        let len: Option<u32> = self
+            .process
+            .read(a + self.string_list_offsets.string_len)
+            .ok();
+        len == Some(utf16.len() as u32) && self.read_utf16_at(a).is_some_and(|w| w == utf16)
+    }
+
+    /// The `_version` of the Mono `Dictionary<K, V>` at `a`.
+    fn read_dictionary_version(&self, a: Address64) -> Option<i32> {
+        self.process
+            .read(a + self.dictionary_offsets.dictionary_version)
+            .ok()
+    }
+
+    /// Looks up `key` in the Mono `Dictionary<string, V>` at `a`,
+    /// producing `Some(None)` if the Dictionary can be read but doesn't have it.
+    fn find_in_dictionary<V: CheckedBitPattern>(
+        &self,
+        a: Address64,
+        key: &str,
+    ) -> Option<Option<V>> {
+        let key_utf16: Vec<u16> = key.encode_utf16().collect();
+        Some(
+            self.read_dictionary::<V>(a)?
+                .into_iter()
+                .find_map(|(k, v)| self.string_eq_utf16(k, &key_utf16).then_some(v)),
+        )
+    }
+
+    /// Finds the item with `id` in `scene`, in the SceneData collection whose `scenes` field
+    /// is `p`, producing `Some(None)` if the game hasn't saved anything for it yet.
+    ///
+    /// Produces None if the item doesn't have that `id` and `scene` where [SceneDataOffsets]
+    /// expects them, since then its `Value` can't be trusted to be where it expects either.
+    fn find_scene_item(
+        &self,
+        p: &dyn NamedPointer,
+        scene: &str,
+        id: &str,
+    ) -> Option<Option<Address64>> {
+        let scenes = self.read_address(p)?;
+        let scenes_version = self.read_dictionary_version(scenes)?;
+        let key = (p.name().to_string(), scene.to_string(), id.to_string());
+        let cached = self
+            .scene_items
+            .borrow()
+            .get(&key)
+            .filter(|s| s.scenes == scenes && s.scenes_version == scenes_version)
+            .filter(|s| {
+                s.items.is_none_or(|(items, version)| {
+                    self.read_dictionary_version(items) == Some(version)
+                })
+            })
+            .map(|s| s.item);
+        if let Some(item) = cached {
+            return Some(item);
+        }
+
+        let items = match self.find_in_dictionary::<Address64>(scenes, scene)? {
+            Some(items) => Some((items, self.read_dictionary_version(items)?)),
+            None => None,
+        };
+        let item = match items {
+            Some((items, _)) => self.find_in_dictionary(items, id)?,
+            None => None,
+        };
+        if let Some(item) = item {
+            let o = &self.scene_data_offsets;
+            let field_is = |offset: u64, s: &str| {
+                let a: Option<Address64> = self.process.read(item + offset).ok();
+                let utf16: Vec<u16> = s.encode_utf16().collect();
+                a.is_some_and(|a| self.string_eq_utf16(a, &utf16))
+            };
+            if !(field_is(o.item_id, id) && field_is(o.item_scene_name, scene)) {
+                if !self.scene_data_layout_mismatch.replace(true) {
+                    runtime::print_message(
+                        "SceneDataOffsets: an item doesn't have its ID and SceneName where expected",
+                    );
+                }
+                return None;
+            }
+        }
+        let mut scene_items = self.scene_items.borrow_mut();
+        // a different `scenes` Dictionary means a different save file was loaded
+        scene_items.retain(|(c, _, _), s| c != p.name() || s.scenes == scenes);
+        let s = SceneItem {
+            scenes,
+            scenes_version,
+            items,
+            item,
+        };
+        scene_items.insert(key, s);
+        Some(item)
+    }
+
+    /// Reads the value of the item with `id` in `scene`, or the default if it isn't there.
+    fn read_scene_value<T: CheckedBitPattern + Default>(
+        &self,
+        p: &dyn NamedPointer,
+        scene: &str,
+        id: &str,
+    ) -> Option<T> {
+        match self.find_scene_item(p, scene, id)? {
+            Some(item) => self
+                .process
+                .read(item + self.scene_data_offsets.item_value)
+                .ok(),
+            None => Some(T::default()),
+        }
+    }
+
+    /// Finds the address of the value of `quest` in the `_entries` array of QuestCompletionData,
+    /// producing `Some(None)` if it isn't there.
+    fn find_quest_value(&self, entries: Address64, quest: &str) -> Option<Option<Address64>> {
+        let o = &self.dictionary_offsets;
+        let len = self.read_array_len(entries)?;
+        // entries are aligned to the key's 8 bytes
+        let size =
+            (o.entry_value + mem::size_of::<RawQuestCompletion>() as u64).next_multiple_of(8);
+        let keys: Vec<Address64> = self.read_array_elements(entries, len, size, o.entry_key)?;
+        let quest_utf16: Vec<u16> = quest.encode_utf16().collect();
+        let i = keys
+            .into_iter()
+            .position(|k| !k.is_null() && self.string_eq_utf16(k, &quest_utf16));
+        let contents = entries + self.string_list_offsets.array_contents;
+        Some(i.map(|i| contents + i as u64 * size + o.entry_value))
+    }
+
+    /// Reads the Mono `HashSet<T>` at `a`, where `T` is a reference type such as a string,
+    /// as the address of each element, skipping the entries that are empty.
+    pub fn read_hash_set(&self, a: Address64) -> Option<Vec<Address64>> {
+        let o = &self.dictionary_offsets;
+        let entries = self.process.read(a + o.hash_set_entries).ok()?;
+        let len = self.read_array_len(entries)?;
+        // entries hold `hashCode`, `next`, and then the element, like a Dictionary without values
+        let size = o.entry_key + mem::size_of::<Address64>() as u64;
+        let elements: Vec<Address64> = self.read_array_elements(entries, len, size, o.entry_key)?;
+        Some(elements.into_iter().filter(|e| !e.is_null()).collect())
+    }
+}
+
+impl GameMemory for Memory<'_> {
+    fn read_field(&self, p: &dyn NamedPointer, buf: &mut [u8]) -> Option<()> {
+        let a = p
+            .deref_offsets(self.process, &self.module, &self.image)
+            .ok()?;
+        self.process.read_into_slice(a, buf).ok()
+    }
+
+    fn read_string(&self, p: &dyn NamedPointer) -> Option<String> {
+        let a = self.read_address(p)?;
+        self.read_string_at(a)
+    }
+
+    fn find_tool(&self, tool_utf16: &[u16], pd: &PlayerDataPointers) -> Option<()> {
+        const MAX_TOOL_ID_LENGTH: usize = 32; // The longest seems to be 20 but I rounded up
+        if tool_utf16.is_empty() || MAX_TOOL_ID_LENGTH < tool_utf16.len() {
+            // an id from the split settings that can't be a tool
+            return None;
+        }
+        runtime::print_message("Scanning tools");
+        let p_entries = self.read_address(&pd.tools_entries)?;
+        self.read_dictionary_entries::<Address64>(p_entries)?
+            .into_iter()
+            .any(|(key, _)| self.string_eq_utf16(key, tool_utf16))
+            .then_some(())
+    }
+
+    fn read_tools(&self, pd: &PlayerDataPointers) -> Option<Vec<String>> {
+        let p_entries = self.read_address(&pd.tools_entries)?;
+        self.read_dictionary_entries::<Address64>(p_entries)?
+            .into_iter()
+            .map(|(key, _)| self.read_string_at(key))
+            .collect()
+    }
+
+    fn read_scene_bool(&self, scene: &str, id: &str, gm: &GameManagerPointers) -> Option<bool> {
+        self.read_scene_value(&gm.scene_bools, scene, id)
+    }
+
+    fn read_relics(&self, pd: &PlayerDataPointers) -> Option<Vec<String>> {
+        let p_entries = self.read_address(&pd.relics_entries)?;
+        self.read_dictionary_entries::<RawRelicData>(p_entries)?
+            .into_iter()
+            .filter(|(_, relic)| relic.is_collected)
+            .map(|(key, _)| self.read_string_at(key))
+            .collect()
+    }
+
+    fn read_quest(&self, quest: &str, pd: &PlayerDataPointers) -> Option<QuestCompletion> {
+        let entries = self.read_address(&pd.quest_entries)?;
+        let mem: &dyn GameMemory = self;
+        let version: Option<i32> = mem.deref(&pd.quest_version).ok();
+        let cached = self
+            .quest_values
+            .borrow()
+            .get(quest)
+            .filter(|q| Some(q.version) == version && q.entries == entries)
+            .map(|q| q.value);
+        let value = match cached {
+            Some(value) => value,
+            None => {
+                let value = self.find_quest_value(entries, quest)?;
+                // without the version, there's no telling when the quest is added
+                if let Some(version) = version {
+                    let q = QuestValue {
+                        version,
+                        entries,
+                        value,
+                    };
+                    self.quest_values.borrow_mut().insert(quest.to_string(), q);
+                }
+                value
+            }
+        };
+        match value {
+            Some(a) => self
+                .process
+                .read::<RawQuestCompletion>(a)
+                .ok()
+                .map(From::from),
+            None => Some(QuestCompletion::default()),
+        }
+    }
+
+    fn read_scene_int(&self, scene: &str, id: &str, gm: &GameManagerPointers) -> Option<i32> {
+        self.read_scene_value(&gm.scene_ints, scene, id)
+    }
+}
+
+// --------------------------------------------------------
+
+/// The game state that splits and load removal read from,
+/// either the live game through [Memory] or a recorded or synthetic stand-in.
+pub trait GameMemory {
+    /// Reads the raw bytes of the field `p` into `buf`.
+    fn read_field(&self, p: &dyn NamedPointer, buf: &mut [u8]) -> Option<()>;
+
+    /// Reads the C# string that the field `p` refers to.
+    fn read_string(&self, p: &dyn NamedPointer) -> Option<String>;
+
+    /// Produces `Some(())` if the tool with id `tool_utf16` has been obtained.
+    fn find_tool(&self, tool_utf16: &[u16], pd: &PlayerDataPointers) -> Option<()>;
+
+    /// Reads the ids of all the tools that have been obtained.
+    fn read_tools(&self, pd: &PlayerDataPointers) -> Option<Vec<String>>;
+
+    /// Reads the ids of all the relics that have been collected.
+    fn read_relics(&self, pd: &PlayerDataPointers) -> Option<Vec<String>>;
+
+    /// Reads the SceneData bool with `id` in `scene`, such as whether a lever has been pulled,
+    /// or false if the game hasn't saved it yet.
+    fn read_scene_bool(&self, scene: &str, id: &str, gm: &GameManagerPointers) -> Option<bool>;
+
+    /// Reads the SceneData int with `id` in `scene`, or 0 if the game hasn't saved it yet.
+    fn read_scene_int(&self, scene: &str, id: &str, gm: &GameManagerPointers) -> Option<i32>;
+
+    /// Reads how far along the quest with id `quest` is,
+    /// or the default if the game doesn't have it yet.
+    fn read_quest(&self, quest: &str, pd: &PlayerDataPointers) -> Option<QuestCompletion>;
+}
+
+impl dyn GameMemory + '_ {
+    pub fn deref<T: CheckedBitPattern>(&self, p: &dyn NamedPointer) -> Result<T, asr::Error>
+    where
+        T::Bits: NoUninit,
+    {
+        let mut bits = T::Bits::zeroed();
+        self.read_field(p, bytemuck::bytes_of_mut(&mut bits))
+            .ok_or(asr::Error {})?;
+        checked::try_cast(bits).map_err(|_| asr::Error {})
     }
 }
 
 // --------------------------------------------------------
 
 pub struct Env<'a> {
-    pub mem: &'a Memory<'a>,
+    pub mem: &'a dyn GameMemory,
     pub pd: &'a PlayerDataPointers,
     pub gm: &'a GameManagerPointers,
 }
 
 impl<'a> Env<'a> {
-    pub fn new(mem: &'a Memory, pd: &'a PlayerDataPointers, gm: &'a GameManagerPointers) -> Self {
+    pub fn new(
+        mem: &'a dyn GameMemory,
+        pd: &'a PlayerDataPointers,
+        gm: &'a GameManagerPointers,
+    ) -> Self {
         Self { mem, pd, gm }
     }
 }
@@ -623,7 +1569,7 @@ impl SceneStore {
         {
             self.prev_scene_name = mem::replace(&mut self.curr_scene_name, csn);
             #[cfg(debug_assertions)]
-            asr::print_message(&format!("curr_scene_name: {}", self.curr_scene_name));
+            runtime::print_message(&format!("curr_scene_name: {}", self.curr_scene_name));
             self.new_data_curr = self.curr_scene_name != self.next_scene_name;
         }
     }
@@ -635,7 +1581,7 @@ impl SceneStore {
         {
             self.next_scene_name = nsn;
             #[cfg(debug_assertions)]
-            asr::print_message(&format!("next_scene_name: {}", self.next_scene_name));
+            runtime::print_message(&format!("next_scene_name: {}", self.next_scene_name));
             self.new_data_next = !self.next_scene_name.is_empty();
         }
     }
@@ -659,7 +1605,7 @@ impl SceneStore {
             self.last_next = true;
             self.split_this_transition = false;
             #[cfg(debug_assertions)]
-            asr::print_message(&format!(
+            runtime::print_message(&format!(
                 "curr {} -> next {}",
                 &self.curr_scene_name, &self.next_scene_name
             ));
@@ -671,7 +1617,7 @@ impl SceneStore {
                 && !is_menu(&self.curr_scene_name)
             {
                 #[cfg(debug_assertions)]
-                asr::print_message(&format!(
+                runtime::print_message(&format!(
                     "IGNORING spurious curr {} during next {}",
                     self.curr_scene_name, self.next_scene_name
                 ));
@@ -680,7 +1626,7 @@ impl SceneStore {
             self.last_next = false;
             self.split_this_transition = false;
             #[cfg(debug_assertions)]
-            asr::print_message(&format!(
+            runtime::print_message(&format!(
                 "prev {} -> curr {}",
                 &self.prev_scene_name, &self.curr_scene_name
             ));
@@ -699,57 +1645,23 @@ impl Default for SceneStore {
 
 // --------------------------------------------------------
 
-pub fn get_tools_version(mem: &Memory, pd: &PlayerDataPointers) -> Option<i32> {
+pub fn get_tools_version(mem: &dyn GameMemory, pd: &PlayerDataPointers) -> Option<i32> {
     mem.deref(&pd.tools_version).ok()
 }
 
-pub fn find_tool(tool_utf16: &[u16], mem: &Memory, pd: &PlayerDataPointers) -> Option<()> {
-    asr::print_message("Scanning tools");
-    const MAX_TOOL_ID_LENGTH: usize = 32; // The longest seems to be 20 but I rounded up
-
-    let buf = &mut [0; MAX_TOOL_ID_LENGTH][..tool_utf16.len()];
-
-    let p_entries = mem.deref::<Address64, _>(&pd.tools_entries).ok()?;
-
-    let len_entries = mem.process.read::<i32>(p_entries + 0x18).ok()?;
-
-    if len_entries > 131 {
-        return None;
-    }
-
-    for i in 0..len_entries {
-        let p_string: Address64 = mem.process.read(p_entries + 0x28 + 0x18 * i).ok()?;
-
-        let len_string: i32 = mem
-            .process
-            .read(p_string + mem.string_list_offsets.string_len)
-            .ok()?;
-
-        if len_string != tool_utf16.len() as i32 {
-            continue;
-        }
-
-        mem.process
-            .read_into_slice(p_string + mem.string_list_offsets.string_contents, buf)
-            .ok()?;
-
-        if buf == tool_utf16 {
-            return Some(());
-        }
-    }
-
-    None
+pub fn get_relics_version(mem: &dyn GameMemory, pd: &PlayerDataPointers) -> Option<i32> {
+    mem.deref(&pd.relics_version).ok()
 }
 
 // --------------------------------------------------------
 
 pub fn get_timer_state(_: Option<&Env>) -> Option<TimerState> {
-    Some(asr::timer::state())
+    Some(runtime::timer::state())
 }
 
 #[cfg(feature = "split-index")]
 pub fn get_timer_current_split_index(_: Option<&Env>) -> Option<Option<u64>> {
-    Some(asr::timer::current_split_index())
+    Some(runtime::timer::current_split_index())
 }
 
 pub fn get_game_state(e: Option<&Env>) -> Option<i32> {
@@ -760,6 +1672,10 @@ pub fn get_health(e: Option<&Env>) -> Option<i32> {
     e?.mem.deref(&e?.pd.health).ok()
 }
 
+pub fn get_play_time(e: Option<&Env>) -> Option<f32> {
+    e?.mem.deref(&e?.pd.play_time).ok()
+}
+
 pub fn get_max_health_base(e: Option<&Env>) -> Option<i32> {
     e?.mem.deref(&e?.pd.max_health_base).ok()
 }
@@ -0,0 +1,164 @@
+use alloc::{
+    collections::BTreeMap,
+    string::{String, ToString},
+    vec::Vec,
+};
+use bytemuck::NoUninit;
+
+use crate::silksong_memory::{
+    GameManagerPointers, GameMemory, NamedPointer, PlayerDataPointers, QuestCompletion,
+};
+
+/// An in-memory [GameMemory], with field values keyed by the name
+/// they are declared with in `GameManagerPointers` and `PlayerDataPointers`.
+///
+/// Fields that were never set fail to read, just like an unresolved pointer would.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Snapshot {
+    fields: BTreeMap<String, Vec<u8>>,
+    strings: BTreeMap<String, String>,
+    tools: Vec<String>,
+    relics: Vec<String>,
+    /// SceneData values by scene name and id
+    scene_bools: BTreeMap<(String, String), bool>,
+    scene_ints: BTreeMap<(String, String), i32>,
+    quests: BTreeMap<String, QuestCompletion>,
+}
+
+impl Snapshot {
+    pub fn new() -> Snapshot {
+        Snapshot::default()
+    }
+
+    /// Sets the field `name` to the bytes of `value`.
+    pub fn set<T: NoUninit>(&mut self, name: &str, value: T) {
+        self.set_bytes(name, bytemuck::bytes_of(&value));
+    }
+
+    pub fn set_bytes(&mut self, name: &str, bytes: &[u8]) {
+        self.fields.insert(name.to_string(), bytes.to_vec());
+    }
+
+    pub fn get_bytes(&self, name: &str) -> Option<&[u8]> {
+        self.fields.get(name).map(Vec::as_slice)
+    }
+
+    pub fn set_string(&mut self, name: &str, value: &str) {
+        self.strings.insert(name.to_string(), value.to_string());
+    }
+
+    pub fn get_string(&self, name: &str) -> Option<&str> {
+        self.strings.get(name).map(String::as_str)
+    }
+
+    /// Removes the field `name`, so that reading it fails again.
+    pub fn remove(&mut self, name: &str) {
+        self.fields.remove(name);
+    }
+
+    /// Removes the string `name`, so that reading it fails again.
+    pub fn remove_string(&mut self, name: &str) {
+        self.strings.remove(name);
+    }
+
+    /// Adds a tool to the tool list, and bumps `tools_version` the way the game does.
+    pub fn add_tool(&mut self, tool: &str) {
+        if !self.has_tool(tool) {
+            self.tools.push(tool.to_string());
+        }
+        let version = self
+            .get_bytes("tools_version")
+            .and_then(|b| bytemuck::try_pod_read_unaligned::<i32>(b.get(..4)?).ok())
+            .unwrap_or_default();
+        self.set("tools_version", version + 1);
+    }
+
+    /// Removes a tool from the tool list, without touching `tools_version`.
+    pub fn remove_tool(&mut self, tool: &str) {
+        self.tools.retain(|t| t != tool);
+    }
+
+    pub fn has_tool(&self, tool: &str) -> bool {
+        self.tools.iter().any(|t| t == tool)
+    }
+
+    pub fn tools(&self) -> &[String] {
+        &self.tools
+    }
+
+    /// Collects a relic, and bumps `relics_version` the way the game does.
+    pub fn collect_relic(&mut self, relic: &str) {
+        if !self.relics.iter().any(|r| r == relic) {
+            self.relics.push(relic.to_string());
+        }
+        let version = self
+            .get_bytes("relics_version")
+            .and_then(|b| bytemuck::try_pod_read_unaligned::<i32>(b.get(..4)?).ok())
+            .unwrap_or_default();
+        self.set("relics_version", version + 1);
+    }
+
+    /// Removes a relic from the relics collected, without touching `relics_version`.
+    pub fn remove_relic(&mut self, relic: &str) {
+        self.relics.retain(|r| r != relic);
+    }
+
+    /// Sets the SceneData bool with `id` in `scene`, such as a lever the game saves as pulled.
+    pub fn set_scene_bool(&mut self, scene: &str, id: &str, value: bool) {
+        self.scene_bools
+            .insert((scene.to_string(), id.to_string()), value);
+    }
+
+    /// Sets the SceneData int with `id` in `scene`.
+    pub fn set_scene_int(&mut self, scene: &str, id: &str, value: i32) {
+        self.scene_ints
+            .insert((scene.to_string(), id.to_string()), value);
+    }
+
+    /// Sets how far along the quest with id `quest` is.
+    pub fn set_quest(&mut self, quest: &str, completion: QuestCompletion) {
+        self.quests.insert(quest.to_string(), completion);
+    }
+}
+
+impl GameMemory for Snapshot {
+    fn read_field(&self, p: &dyn NamedPointer, buf: &mut [u8]) -> Option<()> {
+        // recorded fields may be wider than the type they are read as
+        let bytes = self.fields.get(p.name())?.get(..buf.len())?;
+        buf.copy_from_slice(bytes);
+        Some(())
+    }
+
+    fn read_string(&self, p: &dyn NamedPointer) -> Option<String> {
+        self.strings.get(p.name()).cloned()
+    }
+
+    fn find_tool(&self, tool_utf16: &[u16], _: &PlayerDataPointers) -> Option<()> {
+        self.tools
+            .iter()
+            .any(|t| t.encode_utf16().eq(tool_utf16.iter().copied()))
+            .then_some(())
+    }
+
+    fn read_tools(&self, _: &PlayerDataPointers) -> Option<Vec<String>> {
+        Some(self.tools.clone())
+    }
+
+    fn read_relics(&self, _: &PlayerDataPointers) -> Option<Vec<String>> {
+        Some(self.relics.clone())
+    }
+
+    fn read_scene_bool(&self, scene: &str, id: &str, _: &GameManagerPointers) -> Option<bool> {
+        let key = (scene.to_string(), id.to_string());
+        Some(self.scene_bools.get(&key).copied().unwrap_or_default())
+    }
+
+    fn read_scene_int(&self, scene: &str, id: &str, _: &GameManagerPointers) -> Option<i32> {
+        let key = (scene.to_string(), id.to_string());
+        Some(self.scene_ints.get(&key).copied().unwrap_or_default())
+    }
+
+    fn read_quest(&self, quest: &str, _: &PlayerDataPointers) -> Option<QuestCompletion> {
+        Some(self.quests.get(quest).copied().unwrap_or_default())
+    }
+}
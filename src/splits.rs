@@ -1,19 +1,19 @@
-use alloc::{vec, vec::Vec};
+use alloc::{string::String, vec, vec::Vec};
 use asr::{settings::Gui, watcher::Pair};
-use ugly_widget::{
-    radio_button::{options_str, RadioButtonOptions},
-    store::StoreWidget,
-};
+use ugly_widget::{radio_button::RadioButtonOptions, store::StoreWidget};
 use utf16_lit::utf16;
 
 use crate::{
+    counter::split_identities,
+    insert_option_str,
     silksong_memory::{
         get_at_bench, get_health, get_heart_pieces, get_max_health_base, get_respawn_scene,
-        get_silk_max, get_silk_spool_parts, is_discontinuity_scene, is_menu, Env, SceneStore,
-        CINEMATIC_STAG_TRAVEL, DEATH_RESPAWN_MARKER_INIT, GAME_STATE_PLAYING, MENU_TITLE,
-        NON_MENU_GAME_STATES, OPENING_SCENES,
+        get_silk_max, get_silk_spool_parts, is_discontinuity_scene, is_menu, Env, GameMemory,
+        NamedPointer, QuestCompletion, SceneStore, CINEMATIC_STAG_TRAVEL,
+        DEATH_RESPAWN_MARKER_INIT, GAME_STATE_PLAYING, MENU_TITLE, NON_MENU_GAME_STATES,
+        OPENING_SCENES,
     },
-    store::Store,
+    store::{ConditionProgress, Store},
     timer::{should_split, SplitterAction},
 };
 
@@ -56,6 +56,54 @@ pub enum Split {
     ///
     /// Splits when player HP is 0
     PlayerDeath,
+    /// PlayerData Bool (Event)
+    ///
+    /// Splits when the PlayerData bool named in this split's `field` setting becomes true
+    PlayerDataBool,
+    /// PlayerData Int (Event)
+    ///
+    /// Splits when the PlayerData int named in this split's `field` setting
+    /// reaches the `value` setting, depending on the `comparison` setting:
+    /// `==` when it becomes equal, `>=` when it becomes at least the value,
+    /// or `changed` whenever it changes
+    PlayerDataInt,
+    /// Scene Data Bool (Event)
+    ///
+    /// Splits when the persistent bool with this split's `id` setting in its `scene` setting
+    /// becomes true, such as a lever, a breakable wall, or a chest
+    SceneDataBool,
+    /// Scene Data Int (Event)
+    ///
+    /// Splits when the persistent int with this split's `id` setting in its `scene` setting
+    /// reaches the `value` setting, depending on the `comparison` setting,
+    /// the same way as PlayerData Int
+    SceneDataInt,
+    /// Quest Accepted (Quest)
+    ///
+    /// Splits when the quest with the id in this split's `quest` setting has been accepted
+    QuestAccepted,
+    /// Quest Count (Quest)
+    ///
+    /// Splits when the count toward the objective of the quest with the id in this split's
+    /// `quest` setting reaches its `count` setting
+    QuestCount,
+    /// Quest Completed (Quest)
+    ///
+    /// Splits when the quest with the id in this split's `quest` setting has been completed
+    QuestCompleted,
+    /// Any Of (Misc)
+    ///
+    /// Splits when any of the splits in this split's `conditions` setting would split
+    AnyOf,
+    /// All Of (Misc)
+    ///
+    /// Splits once all of the splits in this split's `conditions` setting have split, in any order
+    AllOf,
+    /// Sequence (Misc)
+    ///
+    /// Splits once the splits in this split's `conditions` setting have split one after another,
+    /// in the order they're listed
+    Sequence,
     /// Any Transition (Transition)
     ///
     /// Splits when entering a transition (only one will split per transition)
@@ -65,6 +113,11 @@ pub enum Split {
     /// Splits when entering a transition
     /// (excludes discontinuities including save states, deaths, and bellway travel)
     TransitionExcludingDiscontinuities,
+    /// Enter Scene (Transition)
+    ///
+    /// Splits when entering the scene named in this split's `scene` setting,
+    /// and if they're set, only from the `previous_scene` and through the `entry_gate`
+    EnterScene,
     // endregion: Start, End, and Menu
 
     // region: MossLands
@@ -784,6 +837,15 @@ pub enum Split {
     ///
     /// Splits when obtaining a Mask Shard or a complete Mask upgrade
     OnObtainMaskShard,
+    /// Any Mask Shard (Fragment)
+    ///
+    /// Splits when obtaining any Mask Shard that doesn't complete a Mask upgrade
+    AnyMaskShard,
+    /// Mask Shards Total (Fragment)
+    ///
+    /// Splits when the total number of Mask Shards obtained,
+    /// counting 4 for each complete Mask upgrade, reaches this split's `count` setting
+    MaskShardsTotal,
     /// Mask Shard 1 (Fragment)
     ///
     /// Splits when getting 1st Mask Shard
@@ -871,6 +933,15 @@ pub enum Split {
     ///
     /// Splits when obtaining a Spool Fragment or a complete Spool upgrade
     OnObtainSpoolFragment,
+    /// Any Spool Fragment (Fragment)
+    ///
+    /// Splits when obtaining any Spool Fragment that doesn't complete a Spool upgrade
+    AnySpoolFragment,
+    /// Spool Fragments Total (Fragment)
+    ///
+    /// Splits when the total number of Spool Fragments obtained,
+    /// counting 2 for each complete Spool upgrade, reaches this split's `count` setting
+    SpoolFragmentsTotal,
     /// Spool Fragment 1 (Fragment)
     ///
     /// Splits when getting 1st Spool Fragment
@@ -984,6 +1055,10 @@ pub enum Split {
     // endregion: CraftingKitLevels
 
     // region: Crests
+    /// Any Crest (Crest)
+    ///
+    /// Splits when unlocking any new Crest
+    AnyCrest,
     /// Reaper Crest (Crest)
     ///
     /// Splits when the Reaper Crest is unlocked
@@ -1067,6 +1142,15 @@ pub enum Split {
     // endregion: Crests
 
     // region: FleaSpecific
+    /// Any Flea (Flea)
+    ///
+    /// Splits when rescuing any flea
+    AnyFlea,
+    /// Fleas Rescued (Flea)
+    ///
+    /// Splits when the number of fleas rescued, in any order,
+    /// reaches this split's `count` setting
+    FleasRescued,
     /// Rescued Flea Hunter's March (Flea)
     ///
     /// Splits after rescuing flea in Ant_03
@@ -1191,6 +1275,10 @@ pub enum Split {
     // endregion: FleaSpecific
 
     // region: Bellways
+    /// Any Bellway (Bellway)
+    ///
+    /// Splits when unlocking any Bellway station
+    AnyBellway,
     /// Putrified Ducts (Bellway)
     ///
     /// Splits after unlocking Putrified Ducts Bellway
@@ -1503,6 +1591,11 @@ pub enum Split {
     ///
     /// Splits when the Caravan Troupe moves to Fleatopia
     CaravanTroupeFleatopia,
+    /// Relics Collected (Event)
+    ///
+    /// Splits when the number of relics collected, in any order,
+    /// reaches this split's `count` setting
+    RelicsCollected,
     /// Scrounge Relic Sold (Event)
     ///
     /// Splits after selling the first relic to Scrounge
@@ -1593,6 +1686,15 @@ pub enum Split {
     // endregion: Abyss
 
     // region: Tools
+    /// Any Tool (Tool)
+    ///
+    /// Splits when obtaining any new tool
+    AnyTool,
+    /// Tool Acquired (Tool)
+    ///
+    /// Splits when obtaining the tool with the id in this split's `tool` setting,
+    /// such as `Straight Pin` or `Tri Pin` for the Threefold Pin
+    ToolAcquired,
     /// Ascendant's Grip (Tool)
     ///
     /// Splits when obtaining the Ascendant's Grip
@@ -1832,17 +1934,173 @@ pub enum Split {
     // endregion: Tools
 }
 
+/// The text settings in a split's row, for the splits that take parameters.
+///
+/// Each one is stored under the `splits_{i}_` prefix of the row, followed by its name,
+/// such as `splits_3_scene`.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct SplitArgs {
+    /// The scene to enter, for [Split::EnterScene],
+    /// or the scene of the item, for [Split::SceneDataBool] and [Split::SceneDataInt]
+    pub scene: String,
+    /// If not empty, the scene to enter `scene` from
+    pub previous_scene: String,
+    /// If not empty, the gate to enter `scene` through
+    pub entry_gate: String,
+    /// The name of the PlayerData field, for [Split::PlayerDataBool] and [Split::PlayerDataInt]
+    pub field: String,
+    /// The id of the item in the `scene`, for [Split::SceneDataBool] and [Split::SceneDataInt]
+    pub id: String,
+    /// How to compare the PlayerData or SceneData int with the `value`: `==`, `>=`, or `changed`
+    pub comparison: String,
+    /// The value to compare the PlayerData or SceneData int with
+    pub value: String,
+    /// The id of the tool, for [Split::ToolAcquired]
+    pub tool: String,
+    /// The id of the quest, for [Split::QuestAccepted], [Split::QuestCount],
+    /// and [Split::QuestCompleted]
+    pub quest: String,
+    /// The splits to combine, separated by commas, for [Split::AnyOf], [Split::AllOf],
+    /// and [Split::Sequence], each optionally followed by its own settings in parentheses
+    pub conditions: String,
+    /// If not empty, the respawn scene that the combined conditions only count in
+    pub respawn_scene: String,
+    /// How many to reach, for [Split::FleasRescued], [Split::MaskShardsTotal],
+    /// [Split::SpoolFragmentsTotal], [Split::RelicsCollected], and [Split::QuestCount]
+    pub count: String,
+}
+
+impl SplitArgs {
+    /// The names of the settings, after the prefix of the row.
+    pub const NAMES: [&'static str; 12] = [
+        "scene",
+        "previous_scene",
+        "entry_gate",
+        "field",
+        "id",
+        "comparison",
+        "value",
+        "tool",
+        "quest",
+        "conditions",
+        "respawn_scene",
+        "count",
+    ];
+
+    pub fn get(&self, name: &str) -> Option<&str> {
+        match name {
+            "scene" => Some(&self.scene),
+            "previous_scene" => Some(&self.previous_scene),
+            "entry_gate" => Some(&self.entry_gate),
+            "field" => Some(&self.field),
+            "id" => Some(&self.id),
+            "comparison" => Some(&self.comparison),
+            "value" => Some(&self.value),
+            "tool" => Some(&self.tool),
+            "quest" => Some(&self.quest),
+            "conditions" => Some(&self.conditions),
+            "respawn_scene" => Some(&self.respawn_scene),
+            "count" => Some(&self.count),
+            _ => None,
+        }
+    }
+
+    /// Sets the setting called `name`, or produces false if there's no such setting.
+    pub fn set(&mut self, name: &str, value: String) -> bool {
+        match name {
+            "scene" => self.scene = value,
+            "previous_scene" => self.previous_scene = value,
+            "entry_gate" => self.entry_gate = value,
+            "field" => self.field = value,
+            "id" => self.id = value,
+            "comparison" => self.comparison = value,
+            "value" => self.value = value,
+            "tool" => self.tool = value,
+            "quest" => self.quest = value,
+            "conditions" => self.conditions = value,
+            "respawn_scene" => self.respawn_scene = value,
+            "count" => self.count = value,
+            _ => return false,
+        }
+        true
+    }
+
+    /// Moves the `args` of each row from lining up with the `old` splits to lining up with
+    /// the `new` splits, given the key of each, so that they follow their row when the list's
+    /// buttons insert, remove, or move a row, or when a row is changed to another split.
+    /// A new row gets empty args.
+    ///
+    /// The list only tells what's in it, not which button changed it, so this finds the
+    /// one change that turns `old` into `new`, which can't tell rows with the same split
+    /// apart when they're next to each other. When it changed more than that at once,
+    /// each split is followed by its [split_identities](crate::counter::split_identities)
+    /// instead, where splits with the same key keep their args in the order they come in.
+    pub fn migrate(args: &[SplitArgs], old: &[String], new: &[String]) -> Vec<SplitArgs> {
+        let rows = row_edit(old, new).unwrap_or_else(|| {
+            let old = split_identities(old.iter().cloned());
+            split_identities(new.iter().cloned())
+                .iter()
+                .map(|id| old.iter().position(|o| o == id))
+                .collect()
+        });
+        rows.into_iter()
+            .map(|j| j.and_then(|j| args.get(j)).cloned().unwrap_or_default())
+            .collect()
+    }
+
+    /// Whether `n` has reached the `count`, or false if the `count` isn't a number.
+    fn count_reached(&self, n: Option<i32>) -> bool {
+        let count = self.count.trim().parse::<i32>();
+        n.zip(count.ok()).is_some_and(|(n, count)| count <= n)
+    }
+
+    /// Whether the change in the `pair` reaches the `value` by the `comparison`.
+    fn int_comparison_reached(&self, pair: Pair<i32>) -> bool {
+        let value = self.value.trim().parse::<i32>();
+        match (self.comparison.trim(), value) {
+            ("changed", _) => pair.changed(),
+            ("==", Ok(v)) => pair.changed_to(&v),
+            (">=", Ok(v)) => pair.old < v && v <= pair.current,
+            _ => false,
+        }
+    }
+}
+
+/// The row of `old` that each row of `new` was, or None for a row that was inserted,
+/// if `new` differs from `old` by at most one row inserted, removed, changed,
+/// or swapped with the row after it.
+fn row_edit(old: &[String], new: &[String]) -> Option<Vec<Option<usize>>> {
+    // the first row that differs, and how many rows after it are the same at the end
+    let first = old.iter().zip(new).take_while(|(o, n)| o == n).count();
+    let common = old.len().min(new.len()) - first;
+    let last = old
+        .iter()
+        .rev()
+        .zip(new.iter().rev())
+        .take(common)
+        .take_while(|(o, n)| o == n)
+        .count();
+    let old_diff = old.len() - first - last;
+    let new_diff = new.len() - first - last;
+    let mut rows: Vec<Option<usize>> = (0..first).map(Some).collect();
+    match (old_diff, new_diff) {
+        // nothing changed, or one row was changed to another split
+        (0, 0) | (1, 1) => rows.extend((first..first + old_diff).map(Some)),
+        (0, 1) => rows.push(None),
+        (1, 0) => (),
+        // moved up or down
+        (2, 2) if old[first] == new[first + 1] && old[first + 1] == new[first] => {
+            rows.extend([Some(first + 1), Some(first)])
+        }
+        _ => return None,
+    }
+    rows.extend((old.len() - last..old.len()).map(Some));
+    Some(rows)
+}
+
 impl StoreWidget for Split {
     fn insert_into(&self, settings_map: &asr::settings::Map, key: &str) -> bool {
-        let new_s = options_str(self);
-        if settings_map
-            .get(key)
-            .is_some_and(|old_v| old_v.get_string().is_some_and(|old_s| old_s == new_s))
-        {
-            return false;
-        }
-        settings_map.insert(key, new_s);
-        true
+        insert_option_str(self, settings_map, key)
     }
 }
 
@@ -1869,7 +2127,12 @@ pub fn menu_splits(
     }
 }
 
-pub fn transition_splits(split: &Split, scenes: &Pair<&str>, e: &Env) -> SplitterAction {
+pub fn transition_splits(
+    split: &Split,
+    args: Option<&SplitArgs>,
+    scenes: &Pair<&str>,
+    e: &Env,
+) -> SplitterAction {
     let Env { mem, pd, gm } = e;
     match split {
         // region: Start, End, and Menu
@@ -1886,6 +2149,13 @@ pub fn transition_splits(split: &Split, scenes: &Pair<&str>, e: &Env) -> Splitte
                 || is_discontinuity_scene(scenes.current)
                 || mem.deref(&pd.health).is_ok_and(|h: i32| h == 0)),
         ),
+        Split::EnterScene => should_split(args.is_some_and(|a| {
+            !a.scene.is_empty()
+                && scenes.changed_to(&a.scene.as_str())
+                && (a.previous_scene.is_empty() || scenes.old == a.previous_scene)
+                && (a.entry_gate.is_empty()
+                    || mem.read_string(&gm.entry_gate_name).unwrap_or_default() == a.entry_gate)
+        })),
         // endregion: Start, End, and Menu
 
         // region: MossLands
@@ -2351,6 +2621,116 @@ fn mask_shard_split(e: &Env, shard: i32) -> bool {
             .is_ok_and(|n: i32| n == current_shards))
 }
 
+/// The total number of Mask Shards, counting 4 for each Mask upgrade.
+fn mask_shards_total(e: &Env) -> Option<i32> {
+    let Env { mem, pd, .. } = e;
+    const START_MASKS: i32 = 5;
+    let masks: i32 = mem.deref(&pd.max_health_base).ok()?;
+    let shards: i32 = mem.deref(&pd.heart_pieces).ok()?;
+    Some((masks - START_MASKS) * 4 + shards)
+}
+
+/// The total number of Spool Fragments, counting 2 for each Spool upgrade.
+fn spool_fragments_total(e: &Env) -> Option<i32> {
+    let Env { mem, pd, .. } = e;
+    const START_SPOOLS: i32 = 9;
+    let spools: i32 = mem.deref(&pd.silk_max).ok()?;
+    let fragments: i32 = mem.deref(&pd.silk_spool_parts).ok()?;
+    Some((spools - START_SPOOLS) * 2 + fragments)
+}
+
+/// How far along the quest in the `quest` setting is, or None if there isn't one.
+fn read_quest(args: &SplitArgs, e: &Env, store: &mut Store) -> Option<QuestCompletion> {
+    Some(store.get_quest_pair(&args.quest, e)?.current)
+}
+
+/// The number of flags that are set, or None if none of them can be read,
+/// such as while PlayerData isn't loaded.
+fn count_flags(mem: &dyn GameMemory, flags: &[&dyn NamedPointer]) -> Option<i32> {
+    let read: Vec<bool> = flags.iter().filter_map(|f| mem.deref(*f).ok()).collect();
+    if read.is_empty() {
+        return None;
+    }
+    Some(read.into_iter().filter(|b| *b).count() as i32)
+}
+
+/// The number of fleas rescued, from the same fields as the FleaSpecific splits.
+pub fn fleas_rescued(e: Option<&Env>) -> Option<i32> {
+    let Env { mem, pd, .. } = e?;
+    count_flags(
+        *mem,
+        &[
+            &pd.savedflea_ant_03,
+            &pd.savedflea_belltown_04,
+            &pd.savedflea_bone_06,
+            &pd.savedflea_bone_east_05,
+            &pd.savedflea_bone_east_10_church,
+            &pd.savedflea_bone_east_17b,
+            &pd.savedflea_coral_24,
+            &pd.savedflea_coral_35,
+            &pd.savedflea_crawl_06,
+            &pd.savedflea_dock_03d,
+            &pd.savedflea_dock_16,
+            &pd.savedflea_dust_09,
+            &pd.savedflea_dust_12,
+            &pd.savedflea_greymoor_06,
+            &pd.savedflea_greymoor_15b,
+            &pd.savedflea_library_01,
+            &pd.savedflea_library_09,
+            &pd.savedflea_peak_05c,
+            &pd.savedflea_shadow_10,
+            &pd.savedflea_shadow_28,
+            &pd.savedflea_shellwood_03,
+            &pd.savedflea_slab_06,
+            &pd.savedflea_slab_cell,
+            &pd.savedflea_song_11,
+            &pd.savedflea_song_14,
+            &pd.savedflea_under_21,
+            &pd.savedflea_under_23,
+            &pd.tamed_giant_flea,
+            &pd.met_troupe_hunter_wild,
+            &pd.caravan_lech_saved,
+        ],
+    )
+}
+
+/// The number of crests unlocked, from the same fields as the Crest splits,
+/// not counting the Witch Crest, which replaces the Cursed Crest.
+fn crests_unlocked(e: Option<&Env>) -> Option<i32> {
+    let Env { mem, pd, .. } = e?;
+    count_flags(
+        *mem,
+        &[
+            &pd.completed_memory_reaper,
+            &pd.completed_memory_wanderer,
+            &pd.completed_memory_beast,
+            &pd.completed_memory_toolmaster,
+            &pd.completed_memory_witch,
+            &pd.completed_memory_shaman,
+        ],
+    )
+}
+
+/// The number of Bellway stations unlocked, from the same fields as the Bellway splits.
+fn bellways_unlocked(e: Option<&Env>) -> Option<i32> {
+    let Env { mem, pd, .. } = e?;
+    count_flags(
+        *mem,
+        &[
+            &pd.unlocked_aqueduct_station,
+            &pd.unlocked_belltown_station,
+            &pd.unlocked_boneforest_east_station,
+            &pd.unlocked_city_station,
+            &pd.unlocked_coral_tower_station,
+            &pd.unlocked_docks_station,
+            &pd.unlocked_greymoor_station,
+            &pd.unlocked_peak_station,
+            &pd.unlocked_shadow_station,
+            &pd.unlocked_shellwood_station,
+        ],
+    )
+}
+
 fn spool_shard_split(e: &Env, shard: i32) -> bool {
     let Env { mem, pd, .. } = e;
     const START_SPOOLS: i32 = 9;
@@ -2364,7 +2744,12 @@ fn spool_shard_split(e: &Env, shard: i32) -> bool {
             .is_ok_and(|n: i32| n == current_shards))
 }
 
-pub fn continuous_splits(split: &Split, e: &Env, store: &mut Store) -> SplitterAction {
+pub fn continuous_splits(
+    split: &Split,
+    args: Option<&SplitArgs>,
+    e: &Env,
+    store: &mut Store,
+) -> SplitterAction {
     let Env { mem, gm, pd } = e;
     let game_state: i32 = mem.deref(&gm.game_state).unwrap_or_default();
     if !NON_MENU_GAME_STATES.contains(&game_state) {
@@ -2383,6 +2768,37 @@ pub fn continuous_splits(split: &Split, e: &Env, store: &mut Store) -> SplitterA
                 .get_i32_pair_bang("health", &get_health, Some(e))
                 .is_some_and(|p| p.changed_to(&0)),
         ),
+        Split::PlayerDataBool => should_split(args.is_some_and(|a| {
+            store
+                .get_player_data_bool_pair(&a.field, e)
+                .is_some_and(|p| p.changed_to(&true))
+        })),
+        Split::PlayerDataInt => should_split(args.is_some_and(|a| {
+            store
+                .get_player_data_i32_pair(&a.field, e)
+                .is_some_and(|p| a.int_comparison_reached(p))
+        })),
+        Split::SceneDataBool => should_split(args.is_some_and(|a| {
+            store
+                .get_scene_bool_pair(&a.scene, &a.id, e)
+                .is_some_and(|p| p.changed_to(&true))
+        })),
+        Split::SceneDataInt => should_split(args.is_some_and(|a| {
+            store
+                .get_scene_i32_pair(&a.scene, &a.id, e)
+                .is_some_and(|p| a.int_comparison_reached(p))
+        })),
+        Split::QuestAccepted => {
+            should_split(args.is_some_and(|a| read_quest(a, e, store).is_some_and(|q| q.accepted)))
+        }
+        Split::QuestCount => {
+            should_split(args.is_some_and(|a| {
+                a.count_reached(read_quest(a, e, store).map(|q| q.completed_count))
+            }))
+        }
+        Split::QuestCompleted => {
+            should_split(args.is_some_and(|a| read_quest(a, e, store).is_some_and(|q| q.completed)))
+        }
         // endregion: Start, End, and Menu
 
         // region: MossLands
@@ -2667,6 +3083,14 @@ pub fn continuous_splits(split: &Split, e: &Env, store: &mut Store) -> SplitterA
                 .is_some_and(|p| p.increased() && p.current < 4);
             should_split(max_hp_increased || shards_increased)
         }
+        Split::AnyMaskShard => should_split(
+            store
+                .get_i32_pair_bang("heart_pieces", &get_heart_pieces, Some(e))
+                .is_some_and(|p| p.increased()),
+        ),
+        Split::MaskShardsTotal => {
+            should_split(args.is_some_and(|a| a.count_reached(mask_shards_total(e))))
+        }
         Split::MaskShard1 => should_split(mask_shard_split(e, 1)),
         Split::MaskShard2 => should_split(mask_shard_split(e, 2)),
         Split::MaskShard3 => should_split(mask_shard_split(e, 3)),
@@ -2700,6 +3124,14 @@ pub fn continuous_splits(split: &Split, e: &Env, store: &mut Store) -> SplitterA
                 .is_some_and(|p| p.increased() && p.current < 2);
             should_split(max_silk_increased || frags_increased)
         }
+        Split::AnySpoolFragment => should_split(
+            store
+                .get_i32_pair_bang("silk_spool_parts", &get_silk_spool_parts, Some(e))
+                .is_some_and(|p| p.increased()),
+        ),
+        Split::SpoolFragmentsTotal => {
+            should_split(args.is_some_and(|a| a.count_reached(spool_fragments_total(e))))
+        }
         Split::SpoolFragment1 => should_split(spool_shard_split(e, 1)),
         Split::Spool1 => should_split(spool_shard_split(e, 2)),
         Split::SpoolFragment3 => should_split(spool_shard_split(e, 3)),
@@ -2755,6 +3187,11 @@ pub fn continuous_splits(split: &Split, e: &Env, store: &mut Store) -> SplitterA
         // endregion: CraftingKitLevels
 
         // region: Crests
+        Split::AnyCrest => should_split(
+            store
+                .get_i32_pair_bang("crests_unlocked", &crests_unlocked, Some(e))
+                .is_some_and(|p| p.increased()),
+        ),
         Split::ReaperCrest => {
             should_split(mem.deref(&pd.completed_memory_reaper).unwrap_or_default())
         }
@@ -2800,6 +3237,14 @@ pub fn continuous_splits(split: &Split, e: &Env, store: &mut Store) -> SplitterA
         // endregion: Crests
 
         // region: FleaSpecific
+        Split::AnyFlea => should_split(
+            store
+                .get_i32_pair_bang("fleas_rescued", &fleas_rescued, Some(e))
+                .is_some_and(|p| p.increased()),
+        ),
+        Split::FleasRescued => {
+            should_split(args.is_some_and(|a| a.count_reached(fleas_rescued(Some(e)))))
+        }
         Split::SavedFleaHuntersMarch => {
             should_split(mem.deref(&pd.savedflea_ant_03).unwrap_or_default())
         }
@@ -2894,6 +3339,11 @@ pub fn continuous_splits(split: &Split, e: &Env, store: &mut Store) -> SplitterA
         // endregion: FleaSpecific
 
         // region: Stations (Bellway)
+        Split::AnyBellway => should_split(
+            store
+                .get_i32_pair_bang("bellways_unlocked", &bellways_unlocked, Some(e))
+                .is_some_and(|p| p.increased()),
+        ),
         Split::PutrifiedDuctsStation => {
             should_split(mem.deref(&pd.unlocked_aqueduct_station).unwrap_or_default())
         }
@@ -3010,7 +3460,7 @@ pub fn continuous_splits(split: &Split, e: &Env, store: &mut Store) -> SplitterA
                 &pd.has_boneforest_map,
                 &pd.has_moss_grotto_map,
             ];
-            should_split(maps.iter().all(|map| mem.deref(map).unwrap_or_default()))
+            should_split(maps.iter().all(|map| mem.deref(*map).unwrap_or_default()))
         }
         // endregion: ShakraEncounters
 
@@ -3129,6 +3579,11 @@ pub fn continuous_splits(split: &Split, e: &Env, store: &mut Store) -> SplitterA
             mem.deref(&pd.caravan_troupe_location)
                 .is_ok_and(|n: i32| n >= 3),
         ),
+        Split::RelicsCollected => {
+            should_split(args.is_some_and(|a| {
+                a.count_reached(store.get_relics_count_pair(e).map(|p| p.current))
+            }))
+        }
         Split::SoldRelic => should_split(
             mem.deref(&pd.belltown_relic_dealer_gave_relic)
                 .unwrap_or_default(),
@@ -3177,6 +3632,10 @@ pub fn continuous_splits(split: &Split, e: &Env, store: &mut Store) -> SplitterA
         // endregion: Abyss
 
         // region Tools
+        Split::AnyTool => {
+            should_split(store.get_tools_count_pair(e).is_some_and(|p| p.increased()))
+        }
+        Split::ToolAcquired => should_split(args.is_some_and(|a| store.has_tool_named(&a.tool, e))),
         Split::StraightPin => should_split(store.has_tool(&utf16!("Straight Pin"), e)),
         Split::ThreefoldPin => should_split(store.has_tool(&utf16!("Tri Pin"), e)),
         Split::StingShard => should_split(store.has_tool(&utf16!("Sting Shard"), e)),
@@ -3243,14 +3702,180 @@ pub fn continuous_splits(split: &Split, e: &Env, store: &mut Store) -> SplitterA
     }
 }
 
-pub fn splits(
+/// Parses the `conditions` setting of a combined split,
+/// or produces None if any of them isn't a split that can be combined.
+///
+/// Each condition is the key of a split, optionally followed by its own settings in parentheses,
+/// such as `PlayerDataBool(field=hasDash)` or `SceneDataInt(scene=Bone_04, id=Lever, value=1)`.
+/// A condition without them gets None, for the settings of the combined split's row.
+fn parse_conditions(conditions: &str) -> Option<Vec<(Split, Option<SplitArgs>)>> {
+    let options = Split::radio_button_options();
+    split_outside_parentheses(conditions)?
+        .into_iter()
+        .map(str::trim)
+        .filter(|c| !c.is_empty())
+        .map(|c| {
+            let (key, args) = match c.split_once('(') {
+                Some((key, rest)) => (key.trim(), Some(parse_condition_args(rest)?)),
+                None => (c, None),
+            };
+            options
+                .iter()
+                .find(|o| o.key == key)
+                .map(|o| o.value.clone())
+                .filter(|s| !is_combined(s))
+                .map(|s| (s, args))
+        })
+        .collect()
+}
+
+/// Splits the `conditions` at the commas between them, but not at the commas between the
+/// settings in parentheses, or produces None if the parentheses don't match up.
+fn split_outside_parentheses(conditions: &str) -> Option<Vec<&str>> {
+    let mut parts = Vec::new();
+    let mut start = 0;
+    let mut inside = false;
+    for (i, c) in conditions.char_indices() {
+        match c {
+            '(' if !inside => inside = true,
+            ')' if inside => inside = false,
+            '(' | ')' => return None,
+            ',' if !inside => {
+                parts.push(&conditions[start..i]);
+                start = i + 1;
+            }
+            _ => (),
+        }
+    }
+    if inside {
+        return None;
+    }
+    parts.push(&conditions[start..]);
+    Some(parts)
+}
+
+/// Parses the settings of a condition, after its opening parenthesis, such as `field=hasDash)`,
+/// or produces None if any of them isn't `name=value` with the name of a setting,
+/// other than the ones only a combined split has.
+fn parse_condition_args(rest: &str) -> Option<SplitArgs> {
+    let inner = rest.trim_end().strip_suffix(')')?;
+    let mut args = SplitArgs::default();
+    for setting in inner.split(',').map(str::trim).filter(|s| !s.is_empty()) {
+        let (name, value) = setting.split_once('=')?;
+        let name = name.trim();
+        if ["conditions", "respawn_scene"].contains(&name) || !args.set(name, value.trim().into()) {
+            return None;
+        }
+    }
+    Some(args)
+}
+
+/// The splits that the `split` checks, each with the args it checks with:
+/// the conditions of a combined split, or else the `split` itself.
+pub fn split_conditions(split: &Split, args: &SplitArgs) -> Vec<(Split, SplitArgs)> {
+    if !is_combined(split) {
+        return vec![(split.clone(), args.clone())];
+    }
+    parse_conditions(&args.conditions)
+        .unwrap_or_default()
+        .into_iter()
+        .map(|(c, c_args)| (c, c_args.unwrap_or_else(|| args.clone())))
+        .collect()
+}
+
+fn is_combined(split: &Split) -> bool {
+    matches!(split, Split::AnyOf | Split::AllOf | Split::Sequence)
+}
+
+/// Splits that combine the splits in the `conditions` setting,
+/// remembering which of them have split so far in the [Store].
+fn combined_splits(
+    split: &Split,
+    args: Option<&SplitArgs>,
+    env: &Env,
+    trans_now: bool,
+    ss: &mut SceneStore,
+    store: &mut Store,
+) -> SplitterAction {
+    let Some(args) = args else {
+        return should_split(false);
+    };
+    let mut progress = store.take_condition_progress();
+    if progress.source != args.conditions {
+        progress = ConditionProgress {
+            source: args.conditions.clone(),
+            conditions: parse_conditions(&args.conditions),
+            done: Vec::new(),
+            last: Vec::new(),
+        };
+    }
+    let Some(conditions) = progress.conditions.as_ref().filter(|cs| !cs.is_empty()) else {
+        store.put_condition_progress(progress);
+        return should_split(false);
+    };
+    progress.done.resize(conditions.len(), false);
+
+    let in_respawn_scene = args.respawn_scene.is_empty()
+        || env
+            .mem
+            .read_string(&env.pd.respawn_scene)
+            .unwrap_or_default()
+            == args.respawn_scene;
+    // every condition is checked on every tick, even once the outcome is known,
+    // so that the values they watch in the Store stay watched
+    let now: Vec<bool> = conditions
+        .iter()
+        .map(|(c, c_args)| {
+            let c_args = c_args.as_ref().unwrap_or(args);
+            split_action(c, Some(c_args), env, trans_now, ss, store) == SplitterAction::Split
+        })
+        .map(|split| split && in_respawn_scene)
+        .collect();
+    let all_done = match split {
+        Split::AnyOf => now.iter().any(|n| *n),
+        Split::AllOf => {
+            for (done, n) in progress.done.iter_mut().zip(now.iter()) {
+                *done |= *n;
+            }
+            progress.done.iter().all(|d| *d)
+        }
+        Split::Sequence => {
+            let last = core::mem::replace(&mut progress.last, now.clone());
+            // each step after the first has to start splitting once the one before it is done,
+            // not just still be splitting from earlier, so more than one in a row only split
+            // on the same tick when they all start to on that tick.
+            // Nothing was splitting before the first tick after the current split changed.
+            while let Some(i) = progress.done.iter().position(|d| !*d) {
+                let started = now[i] && (i == 0 || !last.get(i).copied().unwrap_or(false));
+                if !started {
+                    break;
+                }
+                progress.done[i] = true;
+            }
+            progress.done.iter().all(|d| *d)
+        }
+        _ => false,
+    };
+    if all_done {
+        progress.done.clear();
+    }
+    store.put_condition_progress(progress);
+    should_split(all_done)
+}
+
+/// Produces what the `split` would do on this tick, without marking the transition as split on.
+fn split_action(
     split: &Split,
+    args: Option<&SplitArgs>,
     env: &Env,
     trans_now: bool,
     ss: &mut SceneStore,
     store: &mut Store,
 ) -> SplitterAction {
-    let a1 = continuous_splits(split, env, store).or_else(|| {
+    if is_combined(split) {
+        return combined_splits(split, args, env, trans_now, ss, store);
+    }
+    continuous_splits(split, args, env, store).or_else(|| {
         let scenes = ss.pair();
         let a2 = if !ss.split_this_transition {
             transition_once_splits(split, &scenes, env)
@@ -3262,13 +3887,24 @@ pub fn splits(
                 if is_menu(scenes.old) || is_menu(scenes.current) {
                     menu_splits(split, &scenes, env, store)
                 } else {
-                    transition_splits(split, &scenes, env)
+                    transition_splits(split, args, &scenes, env)
                 }
             } else {
                 SplitterAction::Pass
             }
         })
-    });
+    })
+}
+
+pub fn splits(
+    split: &Split,
+    args: Option<&SplitArgs>,
+    env: &Env,
+    trans_now: bool,
+    ss: &mut SceneStore,
+    store: &mut Store,
+) -> SplitterAction {
+    let a1 = split_action(split, args, env, trans_now, ss, store);
     if a1 != SplitterAction::Pass {
         ss.split_this_transition = true;
     }
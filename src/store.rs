@@ -1,6 +1,8 @@
 use alloc::{
+    boxed::Box,
     collections::BTreeMap,
     string::{String, ToString},
+    vec::Vec,
 };
 use asr::{
     timer::TimerState,
@@ -9,16 +11,25 @@ use asr::{
 
 #[cfg(feature = "split-index")]
 use crate::silksong_memory::get_timer_current_split_index;
-use crate::silksong_memory::{find_tool, get_timer_state, get_tools_version, Env};
+use crate::{
+    silksong_memory::{
+        get_relics_version, get_timer_state, get_tools_version, Env, GameMemory, PlayerDataField,
+        PlayerDataPointers, QuestCompletion,
+    },
+    splits::{Split, SplitArgs},
+};
+
+/// Reads a value for the [Store] to watch, or None if it can't be read right now
+type Getter<A> = Box<dyn Fn(Option<&Env>) -> Option<A>>;
 
 struct StoreValue<A: 'static> {
     watcher: Watcher<A>,
     interested: bool,
-    get: &'static dyn Fn(Option<&Env>) -> Option<A>,
+    get: Getter<A>,
 }
 
 impl<A: Clone + Eq> StoreValue<A> {
-    fn new(get: &'static dyn Fn(Option<&Env>) -> Option<A>, env: Option<&Env>) -> Self {
+    fn new(get: Getter<A>, env: Option<&Env>) -> Self {
         let mut watcher = Watcher::new();
         if let Some(value) = get(env) {
             watcher.update_infallible(value);
@@ -42,7 +53,7 @@ impl<A: Clone + Eq> StoreValue<A> {
 
 pub struct ToolCache {
     version: Option<i32>,
-    tool: &'static [u16],
+    tool: Vec<u16>,
     found: bool,
 }
 
@@ -50,7 +61,7 @@ impl ToolCache {
     fn new() -> Self {
         ToolCache {
             version: None,
-            tool: &[],
+            tool: Vec::new(),
             found: false,
         }
     }
@@ -59,13 +70,13 @@ impl ToolCache {
         match e {
             None => {
                 self.version = None;
-                self.tool = &[]
+                self.tool.clear();
             }
             Some(Env { pd, mem, .. }) => {
-                let new = get_tools_version(mem, pd);
+                let new = get_tools_version(*mem, pd);
                 if self.version != new {
                     self.version = new;
-                    self.tool = &[]
+                    self.tool.clear();
                 }
             }
         }
@@ -77,19 +88,84 @@ impl ToolCache {
         }
     }
 
-    pub fn has_tool(&mut self, tool_utf16: &'static [u16], e: &Env) -> bool {
+    pub fn has_tool(&mut self, tool_utf16: &[u16], e: &Env) -> bool {
         self.update_version(Some(e));
         if self.version.is_none() {
             return false;
         }
         if self.tool != tool_utf16 {
-            self.found = find_tool(tool_utf16, e.mem, e.pd).is_some();
-            self.tool = tool_utf16
+            self.found = e.mem.find_tool(tool_utf16, e.pd).is_some();
+            self.tool.clear();
+            self.tool.extend_from_slice(tool_utf16);
         }
         self.found
     }
 }
 
+/// Reads a PlayerData collection, or its version, which the game bumps when the collection changes
+type CollectionGetter<A> = fn(&dyn GameMemory, &PlayerDataPointers) -> Option<A>;
+
+/// The number of things in a PlayerData collection, such as the tools obtained,
+/// only read again when the collection's version changes, since reading the whole collection is slow.
+struct CollectionCount {
+    get_version: CollectionGetter<i32>,
+    read: CollectionGetter<Vec<String>>,
+    version: Option<i32>,
+    count: Option<i32>,
+    watcher: Watcher<i32>,
+    interested: bool,
+}
+
+impl CollectionCount {
+    fn new(
+        get_version: CollectionGetter<i32>,
+        read: CollectionGetter<Vec<String>>,
+        env: Option<&Env>,
+    ) -> Self {
+        let mut collection_count = CollectionCount {
+            get_version,
+            read,
+            version: None,
+            count: None,
+            watcher: Watcher::new(),
+            interested: true,
+        };
+        collection_count.update(env);
+        collection_count
+    }
+
+    fn update(&mut self, env: Option<&Env>) {
+        let Some(Env { pd, mem, .. }) = env else {
+            self.version = None;
+            self.count = None;
+            return;
+        };
+        let version = (self.get_version)(*mem, pd);
+        if self.version != version || self.count.is_none() {
+            self.version = version;
+            self.count = (self.read)(*mem, pd).map(|c| c.len() as i32);
+        }
+        if let Some(count) = self.count {
+            self.watcher.update_infallible(count);
+        }
+    }
+}
+
+/// How far a combined split has gotten through its conditions.
+#[derive(Default)]
+pub struct ConditionProgress {
+    /// The `conditions` setting that `conditions` was parsed from
+    pub source: String,
+    /// The splits to combine, each with its own settings or None for the settings of the row,
+    /// or None if the setting has something that isn't one
+    pub conditions: Option<Vec<(Split, Option<SplitArgs>)>>,
+    /// Which of the conditions have split so far
+    pub done: Vec<bool>,
+    /// Which of the conditions split on the last tick, or empty if none have been checked
+    /// since the current split changed
+    pub last: Vec<bool>,
+}
+
 pub struct Store {
     timer_state: StoreValue<TimerState>,
     #[cfg(feature = "split-index")]
@@ -97,19 +173,42 @@ pub struct Store {
     bools: BTreeMap<&'static str, StoreValue<bool>>,
     i32s: BTreeMap<&'static str, StoreValue<i32>>,
     strings: BTreeMap<&'static str, StoreValue<String>>,
+    /// PlayerData fields whose names are only known at runtime, from the split settings
+    player_data_bools: BTreeMap<String, StoreValue<bool>>,
+    player_data_i32s: BTreeMap<String, StoreValue<i32>>,
+    /// SceneData values by scene name and id, from the split settings
+    scene_bools: BTreeMap<(String, String), StoreValue<bool>>,
+    scene_i32s: BTreeMap<(String, String), StoreValue<i32>>,
+    /// How far along each quest is, by the quest ids from the split settings
+    quests: BTreeMap<String, StoreValue<QuestCompletion>>,
     tools: ToolCache,
+    /// The last tool id given to [Store::has_tool_named], and its UTF-16 encoding
+    tool_named: (String, Vec<u16>),
+    tools_count: Option<CollectionCount>,
+    relics_count: Option<CollectionCount>,
+    /// Cleared whenever the current split changes
+    condition_progress: ConditionProgress,
 }
 
 impl Store {
     pub fn new() -> Self {
         Self {
-            timer_state: StoreValue::new(&get_timer_state, None),
+            timer_state: StoreValue::new(Box::new(get_timer_state), None),
             #[cfg(feature = "split-index")]
-            split_index: StoreValue::new(&get_timer_current_split_index, None),
+            split_index: StoreValue::new(Box::new(get_timer_current_split_index), None),
             bools: BTreeMap::new(),
             i32s: BTreeMap::new(),
             strings: BTreeMap::new(),
+            player_data_bools: BTreeMap::new(),
+            player_data_i32s: BTreeMap::new(),
+            scene_bools: BTreeMap::new(),
+            scene_i32s: BTreeMap::new(),
+            quests: BTreeMap::new(),
+            condition_progress: ConditionProgress::default(),
             tools: ToolCache::new(),
+            tool_named: (String::new(), Vec::new()),
+            tools_count: None,
+            relics_count: None,
         }
     }
 
@@ -135,10 +234,47 @@ impl Store {
         None
     }
 
-    pub fn has_tool(&mut self, tool_utf16: &'static [u16], e: &Env) -> bool {
+    pub fn take_condition_progress(&mut self) -> ConditionProgress {
+        core::mem::take(&mut self.condition_progress)
+    }
+
+    pub fn put_condition_progress(&mut self, progress: ConditionProgress) {
+        self.condition_progress = progress;
+    }
+
+    pub fn has_tool(&mut self, tool_utf16: &[u16], e: &Env) -> bool {
         self.tools.has_tool(tool_utf16, e)
     }
 
+    /// Like [Store::has_tool], for a tool id that's only known at runtime, such as `"Tri Pin"`.
+    pub fn has_tool_named(&mut self, tool: &str, e: &Env) -> bool {
+        if tool.is_empty() {
+            return false;
+        }
+        if self.tool_named.0 != tool {
+            self.tool_named = (tool.to_string(), tool.encode_utf16().collect());
+        }
+        self.tools.has_tool(&self.tool_named.1, e)
+    }
+
+    /// Watches the number of tools obtained.
+    pub fn get_tools_count_pair(&mut self, e: &Env) -> Option<Pair<i32>> {
+        let v = self.tools_count.get_or_insert_with(|| {
+            CollectionCount::new(get_tools_version, |mem, pd| mem.read_tools(pd), Some(e))
+        });
+        v.interested = true;
+        v.watcher.pair
+    }
+
+    /// Watches the number of relics collected.
+    pub fn get_relics_count_pair(&mut self, e: &Env) -> Option<Pair<i32>> {
+        let v = self.relics_count.get_or_insert_with(|| {
+            CollectionCount::new(get_relics_version, |mem, pd| mem.read_relics(pd), Some(e))
+        });
+        v.interested = true;
+        v.watcher.pair
+    }
+
     pub fn get_bool_pair(&mut self, key: &str) -> Option<Pair<bool>> {
         let v = self.bools.get_mut(key)?;
         v.interested = true;
@@ -164,7 +300,7 @@ impl Store {
         env: Option<&Env>,
     ) -> Option<Pair<bool>> {
         if !self.bools.contains_key(key) {
-            self.bools.insert(key, StoreValue::new(get, env));
+            self.bools.insert(key, StoreValue::new(Box::new(get), env));
         }
         self.get_bool_pair(key)
     }
@@ -176,7 +312,7 @@ impl Store {
         env: Option<&Env>,
     ) -> Option<Pair<i32>> {
         if !self.i32s.contains_key(key) {
-            self.i32s.insert(key, StoreValue::new(get, env));
+            self.i32s.insert(key, StoreValue::new(Box::new(get), env));
         }
         self.get_i32_pair(key)
     }
@@ -188,19 +324,126 @@ impl Store {
         env: Option<&Env>,
     ) -> Option<String> {
         if !self.strings.contains_key(key) {
-            self.strings.insert(key, StoreValue::new(get, env));
+            self.strings
+                .insert(key, StoreValue::new(Box::new(get), env));
         }
         self.get_string(key)
     }
 
+    /// Watches the PlayerData bool called `key` in the game's memory, such as `"hasDash"`.
+    pub fn get_player_data_bool_pair(&mut self, key: &str, env: &Env) -> Option<Pair<bool>> {
+        if key.is_empty() {
+            return None;
+        }
+        if !self.player_data_bools.contains_key(key) {
+            let pointer = PlayerDataField::new(key, core::mem::size_of::<bool>());
+            let get = move |e: Option<&Env>| e?.mem.deref(&pointer).ok();
+            self.player_data_bools
+                .insert(key.to_string(), StoreValue::new(Box::new(get), Some(env)));
+        }
+        let v = self.player_data_bools.get_mut(key)?;
+        v.interested = true;
+        v.watcher.pair
+    }
+
+    /// Watches the PlayerData int called `key` in the game's memory, such as `"silkMax"`.
+    pub fn get_player_data_i32_pair(&mut self, key: &str, env: &Env) -> Option<Pair<i32>> {
+        if key.is_empty() {
+            return None;
+        }
+        if !self.player_data_i32s.contains_key(key) {
+            let pointer = PlayerDataField::new(key, core::mem::size_of::<i32>());
+            let get = move |e: Option<&Env>| e?.mem.deref(&pointer).ok();
+            self.player_data_i32s
+                .insert(key.to_string(), StoreValue::new(Box::new(get), Some(env)));
+        }
+        let v = self.player_data_i32s.get_mut(key)?;
+        v.interested = true;
+        v.watcher.pair
+    }
+
+    /// Watches the SceneData bool with `id` in `scene`, such as a lever or a breakable wall.
+    pub fn get_scene_bool_pair(&mut self, scene: &str, id: &str, env: &Env) -> Option<Pair<bool>> {
+        if scene.is_empty() || id.is_empty() {
+            return None;
+        }
+        let key = (scene.to_string(), id.to_string());
+        if !self.scene_bools.contains_key(&key) {
+            let (s, i) = key.clone();
+            let get = move |e: Option<&Env>| e?.mem.read_scene_bool(&s, &i, e?.gm);
+            self.scene_bools
+                .insert(key.clone(), StoreValue::new(Box::new(get), Some(env)));
+        }
+        let v = self.scene_bools.get_mut(&key)?;
+        v.interested = true;
+        v.watcher.pair
+    }
+
+    /// Watches the SceneData int with `id` in `scene`.
+    pub fn get_scene_i32_pair(&mut self, scene: &str, id: &str, env: &Env) -> Option<Pair<i32>> {
+        if scene.is_empty() || id.is_empty() {
+            return None;
+        }
+        let key = (scene.to_string(), id.to_string());
+        if !self.scene_i32s.contains_key(&key) {
+            let (s, i) = key.clone();
+            let get = move |e: Option<&Env>| e?.mem.read_scene_int(&s, &i, e?.gm);
+            self.scene_i32s
+                .insert(key.clone(), StoreValue::new(Box::new(get), Some(env)));
+        }
+        let v = self.scene_i32s.get_mut(&key)?;
+        v.interested = true;
+        v.watcher.pair
+    }
+
+    /// Watches how far along the quest with id `quest` is,
+    /// so that however many splits ask for it, it's only read once per tick.
+    pub fn get_quest_pair(&mut self, quest: &str, env: &Env) -> Option<Pair<QuestCompletion>> {
+        if quest.is_empty() {
+            return None;
+        }
+        if !self.quests.contains_key(quest) {
+            let q = quest.to_string();
+            let get = move |e: Option<&Env>| e?.mem.read_quest(&q, e?.pd);
+            self.quests
+                .insert(quest.to_string(), StoreValue::new(Box::new(get), Some(env)));
+        }
+        let v = self.quests.get_mut(quest)?;
+        v.interested = true;
+        v.watcher.pair
+    }
+
     pub fn update_all(&mut self, env: Option<&Env>) {
         self.bools.retain(|_, v| v.interested);
         self.i32s.retain(|_, v| v.interested);
         self.strings.retain(|_, v| v.interested);
-        self.timer_state.update(env);
+        self.player_data_bools.retain(|_, v| v.interested);
+        self.player_data_i32s.retain(|_, v| v.interested);
+        self.scene_bools.retain(|_, v| v.interested);
+        self.scene_i32s.retain(|_, v| v.interested);
+        self.quests.retain(|_, v| v.interested);
+        for count in [&mut self.tools_count, &mut self.relics_count] {
+            if count.as_ref().is_some_and(|v| !v.interested) {
+                *count = None;
+            }
+        }
+        let split_changed = self.timer_state.update(env);
         #[cfg(feature = "split-index")]
-        self.split_index.update(env);
+        let split_changed = self.split_index.update(env) || split_changed;
+        if split_changed {
+            self.condition_progress.done.clear();
+            self.condition_progress.last.clear();
+        }
         self.tools.update_validity(env);
+        for v in [&mut self.tools_count, &mut self.relics_count]
+            .into_iter()
+            .flatten()
+        {
+            v.update(env);
+            if v.watcher.pair.is_some_and(|p| p.changed()) {
+                v.interested = false;
+            }
+        }
         for v in self.bools.values_mut() {
             if v.update(env) {
                 v.interested = false;
@@ -216,6 +459,31 @@ impl Store {
                 v.interested = false;
             }
         }
+        for v in self.player_data_bools.values_mut() {
+            if v.update(env) {
+                v.interested = false;
+            }
+        }
+        for v in self.player_data_i32s.values_mut() {
+            if v.update(env) {
+                v.interested = false;
+            }
+        }
+        for v in self.scene_bools.values_mut() {
+            if v.update(env) {
+                v.interested = false;
+            }
+        }
+        for v in self.scene_i32s.values_mut() {
+            if v.update(env) {
+                v.interested = false;
+            }
+        }
+        for v in self.quests.values_mut() {
+            if v.update(env) {
+                v.interested = false;
+            }
+        }
     }
 }
 
@@ -0,0 +1,628 @@
+//! A line-oriented trace of the game state, one line per tick that changed something.
+//!
+//! ```text
+//! trace header version=5 hit_counter=true hit_recoil=true hit_hazard=true hit_health_0=true hit_silk_damage=false hit_double_damage=false hit_damage=false load_removal_mode=Standard remove_hazard_respawns=false remove_fast_travel=false remove_tilemap=false splits=StartNewGame,MossMother,EndingSplit
+//! trace 0 +"Flea Brew" game_state=04000000 scene_name="Tut_01"
+//! trace 57 health=04000000
+//! trace 58 scene_load= next_scene_name!
+//! trace 90 scene_bool:"Bone_04":"Collapse Floor"=true
+//! trace 120 quest:"Rock Rollers"=true,1,false
+//! trace 150 +relic:"Weaver Record"
+//! ```
+//!
+//! Each tick line has the tick number, and then what changed since the previous line:
+//! - `name=<hex>` sets the raw bytes of a field, as many as its type has,
+//!   and `name=` with no bytes makes it unreadable,
+//! - `name="<text>"` sets a string, and `name!` makes it unreadable,
+//! - `+"<tool>"` and `-"<tool>"` add and remove a tool,
+//! - `+relic:"<relic>"` and `-relic:"<relic>"` add and remove a collected relic,
+//! - `scene_bool:"<scene>":"<id>"=<bool>` and `scene_int:"<scene>":"<id>"=<int>` set the
+//!   SceneData value of an item that the split settings name by `scene` and `id`,
+//! - `quest:"<quest>"=<accepted>,<count>,<completed>` sets how far along a quest that the
+//!   split settings name is.
+
+use alloc::{borrow::ToOwned, collections::BTreeMap, format, string::String, vec, vec::Vec};
+use core::{fmt::Write, mem};
+use ugly_widget::radio_button::{options_str, RadioButtonOptions};
+
+use crate::{
+    counter::HitOptions,
+    load_removal::{LoadRemovalMode, LoadRemovalOptions},
+    silksong_memory::{
+        get_relics_version, get_tools_version, Env, NamedPointer, PlayerDataField, QuestCompletion,
+    },
+    snapshot::Snapshot,
+    splits::{split_conditions, Split, SplitArgs},
+};
+
+/// What every trace line starts with, to tell them apart from other log messages
+pub const TRACE_PREFIX: &str = "trace ";
+
+/// The version of the header and the items, bumped whenever what they mean changes.
+///
+/// - 1: the first version, whose headers may be missing any key after `hit_counter`.
+/// - 2: adds `hit_silk_damage`, and always writes every key.
+/// - 3: adds the `scene_bool` and `scene_int` items.
+/// - 4: adds the `quest` items.
+/// - 5: adds the `relic` items.
+const TRACE_VERSION: u32 = 5;
+
+fn string_fields<'a>(e: &'a Env) -> [&'a dyn NamedPointer; 5] {
+    [
+        &e.gm.scene_name,
+        &e.gm.next_scene_name,
+        &e.gm.entry_gate_name,
+        &e.pd.respawn_scene,
+        &e.pd.current_crest_id,
+    ]
+}
+
+// --------------------------------------------------------
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TraceHeader {
+    pub hit_counter: bool,
+    pub hit_options: HitOptions,
+    pub load_removal: LoadRemovalOptions,
+    pub splits: Vec<Split>,
+    /// The text settings in each split's row, lined up with `splits`
+    pub split_args: Vec<SplitArgs>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum TraceItem {
+    Field(String, Option<Vec<u8>>),
+    String(String, Option<String>),
+    ToolAdded(String),
+    ToolRemoved(String),
+    RelicAdded(String),
+    RelicRemoved(String),
+    /// The scene, the id, and the value of a SceneData bool
+    SceneBool(String, String, bool),
+    /// The scene, the id, and the value of a SceneData int
+    SceneInt(String, String, i32),
+    Quest(String, QuestCompletion),
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TraceTick {
+    pub tick: u64,
+    pub items: Vec<TraceItem>,
+}
+
+impl TraceTick {
+    pub fn apply(&self, snapshot: &mut Snapshot) {
+        for item in self.items.iter() {
+            match item {
+                TraceItem::Field(name, Some(bytes)) => snapshot.set_bytes(name, bytes),
+                TraceItem::Field(name, None) => snapshot.remove(name),
+                TraceItem::String(name, Some(s)) => snapshot.set_string(name, s),
+                TraceItem::String(name, None) => snapshot.remove_string(name),
+                TraceItem::ToolAdded(tool) => snapshot.add_tool(tool),
+                TraceItem::ToolRemoved(tool) => snapshot.remove_tool(tool),
+                TraceItem::RelicAdded(relic) => snapshot.collect_relic(relic),
+                TraceItem::RelicRemoved(relic) => snapshot.remove_relic(relic),
+                TraceItem::SceneBool(scene, id, b) => snapshot.set_scene_bool(scene, id, *b),
+                TraceItem::SceneInt(scene, id, i) => snapshot.set_scene_int(scene, id, *i),
+                TraceItem::Quest(quest, q) => snapshot.set_quest(quest, *q),
+            }
+        }
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum TraceLine {
+    Header(TraceHeader),
+    Tick(TraceTick),
+}
+
+// --------------------------------------------------------
+
+/// Produces the trace lines while recording, remembering what the previous line left off at.
+#[derive(Default)]
+pub struct TraceRecorder {
+    tick: u64,
+    fields: BTreeMap<String, Vec<u8>>,
+    strings: BTreeMap<String, String>,
+    tools_version: Option<i32>,
+    tools: Vec<String>,
+    relics_version: Option<i32>,
+    relics: Vec<String>,
+    /// PlayerData fields named in the split settings, which aren't declared fields
+    player_data: Vec<PlayerDataField>,
+    /// The SceneData items named in the split settings, by scene and id
+    scene_items: Vec<(String, String)>,
+    scene_bools: BTreeMap<(String, String), bool>,
+    scene_ints: BTreeMap<(String, String), i32>,
+    /// The quests named in the split settings
+    quests: BTreeMap<String, Option<QuestCompletion>>,
+}
+
+impl TraceRecorder {
+    pub fn new() -> TraceRecorder {
+        TraceRecorder::default()
+    }
+
+    /// Also records the PlayerData fields that the splits name in their `field` settings,
+    /// as wide as the splits read them, the SceneData items they name
+    /// in their `scene` and `id` settings, and the quests they name in their `quest` settings.
+    pub fn with_split_args(mut self, splits: &[Split], split_args: &[SplitArgs]) -> TraceRecorder {
+        for (split, args) in splits.iter().zip(split_args) {
+            for (condition, args) in split_conditions(split, args) {
+                self.add_split_args(&condition, &args);
+            }
+        }
+        self
+    }
+
+    fn add_split_args(&mut self, split: &Split, args: &SplitArgs) {
+        let width = match split {
+            Split::PlayerDataBool => Some(mem::size_of::<bool>()),
+            Split::PlayerDataInt => Some(mem::size_of::<i32>()),
+            _ => None,
+        };
+        if let Some(width) = width.filter(|_| !args.field.is_empty()) {
+            match self.player_data.iter_mut().find(|p| p.name == args.field) {
+                Some(p) => p.width = p.width.max(width),
+                None => self
+                    .player_data
+                    .push(PlayerDataField::new(&args.field, width)),
+            }
+        }
+        let item = (args.scene.clone(), args.id.clone());
+        if !item.0.is_empty() && !item.1.is_empty() && !self.scene_items.contains(&item) {
+            self.scene_items.push(item);
+        }
+        if !args.quest.is_empty() {
+            self.quests.entry(args.quest.clone()).or_default();
+        }
+    }
+
+    pub fn header(h: &TraceHeader) -> String {
+        let splits: Vec<String> = h.splits.iter().map(options_str).collect();
+        let mut line = format!(
+            "{}header version={} hit_counter={} hit_recoil={} hit_hazard={} hit_health_0={} hit_silk_damage={} hit_double_damage={} hit_damage={} load_removal_mode={} remove_hazard_respawns={} remove_fast_travel={} remove_tilemap={} splits={}",
+            TRACE_PREFIX,
+            TRACE_VERSION,
+            h.hit_counter,
+            h.hit_options.recoil,
+            h.hit_options.hazard,
+            h.hit_options.health_0,
+            h.hit_options.silk_damage,
+            h.hit_options.double_damage,
+            h.hit_options.damage,
+            options_str(&h.load_removal.mode),
+            h.load_removal.hazard_respawns,
+            h.load_removal.fast_travel,
+            h.load_removal.tilemap,
+            splits.join(",")
+        );
+        for (i, args) in h.split_args.iter().enumerate() {
+            for name in SplitArgs::NAMES {
+                let value = args.get(name).unwrap_or_default();
+                if !value.is_empty() {
+                    let _ = write!(line, " splits_{}_{}=", i, name);
+                    write_quoted(&mut line, value);
+                }
+            }
+        }
+        line
+    }
+
+    /// Produces the line for this tick, or None if nothing changed.
+    pub fn record(&mut self, e: &Env) -> Option<String> {
+        let tick = self.tick;
+        self.tick += 1;
+        let mut line = format!("{}{}", TRACE_PREFIX, tick);
+        let start_len = line.len();
+
+        // tools and relics before fields, so that replaying them doesn't override their versions
+        let tools_version = get_tools_version(e.mem, e.pd);
+        if tools_version != self.tools_version {
+            self.tools_version = tools_version;
+            let tools = e.mem.read_tools(e.pd).unwrap_or_default();
+            for t in tools.iter().filter(|t| !self.tools.contains(t)) {
+                line.push_str(" +");
+                write_quoted(&mut line, t);
+            }
+            for t in self.tools.iter().filter(|t| !tools.contains(t)) {
+                line.push_str(" -");
+                write_quoted(&mut line, t);
+            }
+            self.tools = tools;
+        }
+        let relics_version = get_relics_version(e.mem, e.pd);
+        if relics_version != self.relics_version {
+            self.relics_version = relics_version;
+            let relics = e.mem.read_relics(e.pd).unwrap_or_default();
+            for r in relics.iter().filter(|r| !self.relics.contains(r)) {
+                line.push_str(" +relic:");
+                write_quoted(&mut line, r);
+            }
+            for r in self.relics.iter().filter(|r| !relics.contains(r)) {
+                line.push_str(" -relic:");
+                write_quoted(&mut line, r);
+            }
+            self.relics = relics;
+        }
+
+        let player_data = self.player_data.iter().map(|p| p as &dyn NamedPointer);
+        for p in
+            e.gm.fields()
+                .into_iter()
+                .chain(e.pd.fields())
+                .chain(player_data)
+        {
+            let mut buf = vec![0; p.width()];
+            let new = e.mem.read_field(p, &mut buf).map(|_| buf);
+            let old = self.fields.get(p.name());
+            if new.as_ref() == old {
+                continue;
+            }
+            let _ = write!(line, " {}=", p.name());
+            if let Some(bytes) = new {
+                for b in bytes.iter() {
+                    let _ = write!(line, "{:02x}", b);
+                }
+                self.fields.insert(p.name().to_owned(), bytes);
+            } else {
+                self.fields.remove(p.name());
+            }
+        }
+
+        for p in string_fields(e) {
+            let new = e.mem.read_string(p);
+            let old = self.strings.get(p.name());
+            if new.as_ref() == old {
+                continue;
+            }
+            let _ = write!(line, " {}", p.name());
+            if let Some(s) = new {
+                line.push('=');
+                write_quoted(&mut line, &s);
+                self.strings.insert(p.name().to_owned(), s);
+            } else {
+                line.push('!');
+                self.strings.remove(p.name());
+            }
+        }
+
+        // the items are read as both, since the settings don't say which collection they're in
+        for item in self.scene_items.iter() {
+            let (scene, id) = item;
+            let new = e.mem.read_scene_bool(scene, id, e.gm);
+            if let Some(b) = new.filter(|b| self.scene_bools.get(item) != Some(b)) {
+                line.push_str(" scene_bool:");
+                write_scene_item(&mut line, scene, id);
+                let _ = write!(line, "={}", b);
+                self.scene_bools.insert(item.clone(), b);
+            }
+            let new = e.mem.read_scene_int(scene, id, e.gm);
+            if let Some(i) = new.filter(|i| self.scene_ints.get(item) != Some(i)) {
+                line.push_str(" scene_int:");
+                write_scene_item(&mut line, scene, id);
+                let _ = write!(line, "={}", i);
+                self.scene_ints.insert(item.clone(), i);
+            }
+        }
+
+        for (quest, old) in self.quests.iter_mut() {
+            let new = e.mem.read_quest(quest, e.pd);
+            if let Some(q) = new.filter(|q| old.as_ref() != Some(q)) {
+                line.push_str(" quest:");
+                write_quoted(&mut line, quest);
+                let _ = write!(
+                    line,
+                    "={},{},{}",
+                    q.accepted, q.completed_count, q.completed
+                );
+                *old = Some(q);
+            }
+        }
+
+        (line.len() != start_len).then_some(line)
+    }
+}
+
+fn write_scene_item(out: &mut String, scene: &str, id: &str) {
+    write_quoted(out, scene);
+    out.push(':');
+    write_quoted(out, id);
+}
+
+fn write_quoted(out: &mut String, s: &str) {
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
+
+// --------------------------------------------------------
+
+/// Parses one line of a trace, ignoring anything before the [TRACE_PREFIX],
+/// so that lines can be taken straight from a log.
+///
+/// Produces None for lines that aren't part of a trace, and Err for trace lines that are malformed.
+pub fn parse_line(line: &str) -> Option<Result<TraceLine, String>> {
+    let (_, rest) = line.split_once(TRACE_PREFIX)?;
+    let rest = rest.trim_end();
+    if let Some(header) = rest.strip_prefix("header ") {
+        return Some(parse_header(header).map(TraceLine::Header));
+    }
+    let (tick, items) = rest.split_once(' ').unwrap_or((rest, ""));
+    let Ok(tick) = tick.parse() else {
+        return Some(Err(format!("bad tick: {:?}", tick)));
+    };
+    Some(parse_items(items).map(|items| TraceLine::Tick(TraceTick { tick, items })))
+}
+
+/// The header that a trace of `version` means when it leaves keys out,
+/// or None if this build can't read that version.
+fn header_defaults(version: u32) -> Option<TraceHeader> {
+    let (hit_options, load_removal) = match version {
+        // what the hit counter and load removal did before they had settings
+        1 => (
+            HitOptions {
+                recoil: true,
+                hazard: true,
+                health_0: true,
+                silk_damage: false,
+                double_damage: false,
+                damage: false,
+            },
+            LoadRemovalOptions {
+                mode: LoadRemovalMode::Standard,
+                hazard_respawns: false,
+                fast_travel: false,
+                tilemap: false,
+            },
+        ),
+        // later versions only add items
+        2..=TRACE_VERSION => (HitOptions::default(), LoadRemovalOptions::default()),
+        _ => return None,
+    };
+    Some(TraceHeader {
+        hit_counter: false,
+        hit_options,
+        load_removal,
+        splits: Vec::new(),
+        split_args: Vec::new(),
+    })
+}
+
+fn parse_header(s: &str) -> Result<TraceHeader, String> {
+    let s = s.trim_start();
+    let (version, mut rest) = s.split_once(' ').unwrap_or((s, ""));
+    let version = version
+        .strip_prefix("version=")
+        .ok_or_else(|| format!("header doesn't start with its version: {}", version))?;
+    let mut header = version
+        .parse()
+        .ok()
+        .and_then(header_defaults)
+        .ok_or_else(|| format!("unsupported trace version: {}", version))?;
+    rest = rest.trim_start();
+    while !rest.is_empty() {
+        if let Some((k, r)) = rest
+            .split_once('=')
+            .filter(|(k, r)| !k.contains(' ') && r.starts_with('"'))
+        {
+            let (value, r) = parse_quoted(r)?;
+            set_split_arg(&mut header, k, value)?;
+            rest = r.trim_start();
+            continue;
+        }
+        let end = rest.find(' ').unwrap_or(rest.len());
+        let kv = &rest[..end];
+        rest = rest[end..].trim_start();
+        match kv.split_once('=') {
+            Some(("hit_counter", v)) => {
+                header.hit_counter = parse_bool("hit_counter", v)?;
+            }
+            Some(("hit_recoil", v)) => {
+                header.hit_options.recoil = parse_bool("hit_recoil", v)?;
+            }
+            Some(("hit_hazard", v)) => {
+                header.hit_options.hazard = parse_bool("hit_hazard", v)?;
+            }
+            Some(("hit_health_0", v)) => {
+                header.hit_options.health_0 = parse_bool("hit_health_0", v)?;
+            }
+            Some(("hit_silk_damage", v)) => {
+                header.hit_options.silk_damage = parse_bool("hit_silk_damage", v)?;
+            }
+            Some(("hit_double_damage", v)) => {
+                header.hit_options.double_damage = parse_bool("hit_double_damage", v)?;
+            }
+            Some(("hit_damage", v)) => {
+                header.hit_options.damage = parse_bool("hit_damage", v)?;
+            }
+            Some(("load_removal_mode", v)) => {
+                header.load_removal.mode =
+                    parse_option(v).ok_or_else(|| format!("unknown load_removal_mode: {}", v))?;
+            }
+            Some(("remove_hazard_respawns", v)) => {
+                header.load_removal.hazard_respawns = parse_bool("remove_hazard_respawns", v)?;
+            }
+            Some(("remove_fast_travel", v)) => {
+                header.load_removal.fast_travel = parse_bool("remove_fast_travel", v)?;
+            }
+            Some(("remove_tilemap", v)) => {
+                header.load_removal.tilemap = parse_bool("remove_tilemap", v)?;
+            }
+            Some(("splits", v)) => {
+                header.splits = v
+                    .split(',')
+                    .filter(|k| !k.is_empty())
+                    .map(|k| parse_option(k).ok_or_else(|| format!("unknown split: {}", k)))
+                    .collect::<Result<_, _>>()?;
+            }
+            _ => return Err(format!("bad header entry: {}", kv)),
+        }
+    }
+    Ok(header)
+}
+
+/// Sets a text setting written as `splits_{i}_{name}="<text>"`.
+fn set_split_arg(header: &mut TraceHeader, key: &str, value: String) -> Result<(), String> {
+    let bad = || format!("bad header entry: {}", key);
+    let (i, name) = key
+        .strip_prefix("splits_")
+        .and_then(|r| r.split_once('_'))
+        .ok_or_else(bad)?;
+    let i: usize = i.parse().map_err(|_| bad())?;
+    if header.split_args.len() <= i {
+        header.split_args.resize(i + 1, SplitArgs::default());
+    }
+    if !header.split_args[i].set(name, value) {
+        return Err(bad());
+    }
+    Ok(())
+}
+
+fn parse_bool(name: &str, v: &str) -> Result<bool, String> {
+    v.parse().map_err(|_| format!("bad {}: {}", name, v))
+}
+
+fn parse_option<T: RadioButtonOptions>(key: &str) -> Option<T> {
+    T::radio_button_options()
+        .into_iter()
+        .find(|o| o.key == key)
+        .map(|o| o.value)
+}
+
+fn parse_items(s: &str) -> Result<Vec<TraceItem>, String> {
+    let mut items = Vec::new();
+    let mut rest = s.trim_start();
+    while !rest.is_empty() {
+        let item;
+        if let Some(r) = rest.strip_prefix("+relic:") {
+            let (relic, r) = parse_quoted(r)?;
+            item = TraceItem::RelicAdded(relic);
+            rest = r;
+        } else if let Some(r) = rest.strip_prefix("-relic:") {
+            let (relic, r) = parse_quoted(r)?;
+            item = TraceItem::RelicRemoved(relic);
+            rest = r;
+        } else if let Some(r) = rest.strip_prefix('+') {
+            let (tool, r) = parse_quoted(r)?;
+            item = TraceItem::ToolAdded(tool);
+            rest = r;
+        } else if let Some(r) = rest.strip_prefix('-') {
+            let (tool, r) = parse_quoted(r)?;
+            item = TraceItem::ToolRemoved(tool);
+            rest = r;
+        } else if let Some(r) = rest.strip_prefix("scene_bool:") {
+            let (scene, id, v, r) = parse_scene_item(r)?;
+            let b = parse_bool("scene_bool", v)?;
+            item = TraceItem::SceneBool(scene, id, b);
+            rest = r;
+        } else if let Some(r) = rest.strip_prefix("scene_int:") {
+            let (scene, id, v, r) = parse_scene_item(r)?;
+            let i = v.parse().map_err(|_| format!("bad scene_int: {}", v))?;
+            item = TraceItem::SceneInt(scene, id, i);
+            rest = r;
+        } else if let Some(r) = rest.strip_prefix("quest:") {
+            let (quest, r) = parse_quoted(r)?;
+            let bad = || format!("bad quest: {}", r);
+            let r = r.strip_prefix('=').ok_or_else(bad)?;
+            let end = r.find(' ').unwrap_or(r.len());
+            let mut values = r[..end].split(',');
+            let mut next = || values.next().ok_or_else(bad);
+            let q = QuestCompletion {
+                accepted: parse_bool("quest", next()?)?,
+                completed_count: next()?.parse().map_err(|_| bad())?,
+                completed: parse_bool("quest", next()?)?,
+            };
+            if values.next().is_some() {
+                return Err(bad());
+            }
+            item = TraceItem::Quest(quest, q);
+            rest = &r[end..];
+        } else {
+            let end = rest
+                .find(['=', '!', ' '])
+                .ok_or_else(|| format!("bad item: {}", rest))?;
+            let name = rest[..end].to_owned();
+            let r = &rest[end..];
+            if let Some(r) = r.strip_prefix('!') {
+                item = TraceItem::String(name, None);
+                rest = r;
+            } else if let Some(r) = r.strip_prefix('=').filter(|r| r.starts_with('"')) {
+                let (s, r) = parse_quoted(r)?;
+                item = TraceItem::String(name, Some(s));
+                rest = r;
+            } else if let Some(r) = r.strip_prefix('=') {
+                let hex_end = r.find(' ').unwrap_or(r.len());
+                let bytes = parse_hex(&r[..hex_end])?;
+                item = TraceItem::Field(name, (!bytes.is_empty()).then_some(bytes));
+                rest = &r[hex_end..];
+            } else {
+                return Err(format!("bad item: {}", rest));
+            }
+        }
+        items.push(item);
+        rest = rest.trim_start();
+    }
+    Ok(items)
+}
+
+/// Parses `"<scene>":"<id>"=<value>`, producing the scene, the id, the value,
+/// and the rest of the input.
+fn parse_scene_item(s: &str) -> Result<(String, String, &str, &str), String> {
+    let (scene, r) = parse_quoted(s)?;
+    let r = r
+        .strip_prefix(':')
+        .ok_or_else(|| format!("bad scene item: {}", s))?;
+    let (id, r) = parse_quoted(r)?;
+    let r = r
+        .strip_prefix('=')
+        .ok_or_else(|| format!("bad scene item: {}", s))?;
+    let end = r.find(' ').unwrap_or(r.len());
+    Ok((scene, id, &r[..end], &r[end..]))
+}
+
+/// Parses a string written by [write_quoted], producing it along with the rest of the input.
+fn parse_quoted(s: &str) -> Result<(String, &str), String> {
+    let body = s
+        .strip_prefix('"')
+        .ok_or_else(|| format!("expected a string: {}", s))?;
+    let mut out = String::new();
+    let mut chars = body.char_indices();
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '"' => return Ok((out, &body[i + 1..])),
+            '\\' => match chars.next() {
+                Some((_, 'n')) => out.push('\n'),
+                Some((_, 'r')) => out.push('\r'),
+                Some((_, 't')) => out.push('\t'),
+                Some((_, c)) => out.push(c),
+                None => break,
+            },
+            c => out.push(c),
+        }
+    }
+    Err(format!("unterminated string: {}", s))
+}
+
+fn parse_hex(s: &str) -> Result<Vec<u8>, String> {
+    if s.len() % 2 == 1 {
+        return Err(format!("bad hex: {}", s));
+    }
+    (0..s.len())
+        .step_by(2)
+        .map(|i| {
+            s.get(i..i + 2)
+                .and_then(|b| u8::from_str_radix(b, 16).ok())
+                .ok_or_else(|| format!("bad hex: {}", s))
+        })
+        .collect()
+}
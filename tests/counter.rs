@@ -0,0 +1,175 @@
+// cargo test --target $(rustc -vV | sed -n 's|host: ||p')
+// cargo test --target aarch64-apple-darwin
+// cargo test --target x86_64-apple-darwin
+
+//! Tests for the saved comparisons of a [SegmentCounter]: how [SegmentCounter::merge_run] saves
+//! each run into them, and how they follow the splits through [SegmentCounter::migrate]
+//! when the splits change.
+
+#![cfg(not(target_os = "unknown"))]
+
+use silksong_autosplit_wasm::counter::{
+    split_identities, CounterComparison, SegmentCounter, AVERAGE_RUNS, HIT_KEYS, HIT_VARIABLES,
+};
+
+fn ids(keys: &[&str]) -> Vec<String> {
+    split_identities(keys.iter().map(|k| k.to_string()))
+}
+
+/// A counter with comparisons for Start, A, B, End:
+/// 1 hit to A, 2 more to B, and 3 more to the End.
+fn counter() -> SegmentCounter {
+    let mut c = SegmentCounter::new(HIT_KEYS, HIT_VARIABLES);
+    c.pb = vec![Some(1), Some(3), Some(6)];
+    c.last_attempt = vec![Some(2), Some(4), Some(8)];
+    c.average = vec![Some((10, 5)), Some((20, 5)), Some((30, 5))];
+    c.best_segments = vec![Some(1), Some(2), Some(3)];
+    c
+}
+
+/// Saves a run with the counts in each of its `segments`, every one of them split.
+fn merge(c: &mut SegmentCounter, segments: &[i64], ended: bool) {
+    c.reset();
+    c.segment = segments.to_vec();
+    c.cumulative = segments
+        .iter()
+        .scan(0, |total, n| {
+            *total += n;
+            Some(*total)
+        })
+        .collect();
+    c.total = segments.iter().sum();
+    c.merge_run(ended, &vec![true; segments.len()]);
+}
+
+#[test]
+fn only_a_better_finished_run_replaces_the_pb() {
+    let mut c = SegmentCounter::new(HIT_KEYS, HIT_VARIABLES);
+    merge(&mut c, &[1, 2, 3], true);
+    assert_eq!(c.pb, [Some(1), Some(3), Some(6)]);
+    // worse
+    merge(&mut c, &[0, 5, 5], true);
+    assert_eq!(c.pb, [Some(1), Some(3), Some(6)]);
+    // better, but reset before the end
+    merge(&mut c, &[0, 0], false);
+    assert_eq!(c.pb, [Some(1), Some(3), Some(6)]);
+    assert_eq!(c.last_attempt, [Some(0), Some(0)]);
+    merge(&mut c, &[1, 1, 1], true);
+    assert_eq!(c.pb, [Some(1), Some(2), Some(3)]);
+}
+
+#[test]
+fn the_average_fades_out_older_runs() {
+    let mut c = SegmentCounter::new(HIT_KEYS, HIT_VARIABLES);
+    c.select(CounterComparison::Average);
+    merge(&mut c, &[4], true);
+    merge(&mut c, &[1], true);
+    assert_eq!(c.average, [Some((5, 2))]);
+    // rounded to the nearest
+    assert_eq!(c.comparison, [Some(3)]);
+
+    let mut c = SegmentCounter::new(HIT_KEYS, HIT_VARIABLES);
+    c.select(CounterComparison::Average);
+    for _ in 0..AVERAGE_RUNS {
+        merge(&mut c, &[10], true);
+    }
+    assert_eq!(c.average, [Some((10 * AVERAGE_RUNS, AVERAGE_RUNS))]);
+    // past the number of runs, the next one takes the place of an average run
+    merge(&mut c, &[0], true);
+    assert_eq!(c.average, [Some((9 * AVERAGE_RUNS, AVERAGE_RUNS))]);
+    assert_eq!(c.comparison, [Some(9)]);
+}
+
+#[test]
+fn the_sum_of_best_adds_up_the_best_segments() {
+    let mut c = SegmentCounter::new(HIT_KEYS, HIT_VARIABLES);
+    c.select(CounterComparison::SumOfBest);
+    merge(&mut c, &[3, 1, 2], true);
+    merge(&mut c, &[1, 3, 2], true);
+    assert_eq!(c.best_segments, [Some(1), Some(1), Some(2)]);
+    assert_eq!(c.comparison, [Some(1), Some(2), Some(4)]);
+}
+
+#[test]
+fn a_segment_after_a_skip_isnt_a_best_segment() {
+    let mut c = SegmentCounter::new(HIT_KEYS, HIT_VARIABLES);
+    c.segment = vec![0, 0, 1];
+    c.cumulative = vec![0, 0, 1];
+    c.total = 1;
+    // the first segment was skipped, so the second one started partway through
+    c.merge_run(true, &[false, true, true]);
+    assert_eq!(c.best_segments, [None, None, Some(1)]);
+}
+
+#[test]
+fn identities_count_repeated_keys() {
+    assert_eq!(
+        ids(&["Start", "Bell", "A", "Bell", "End"]),
+        ["Start#0", "Bell#0", "A#0", "Bell#1", "End#0"]
+    );
+}
+
+#[test]
+fn inserting_a_split_keeps_the_totals_around_it() {
+    let mut c = counter();
+    c.migrate(
+        &ids(&["Start", "A", "B", "End"]),
+        &ids(&["Start", "A", "X", "B", "End"]),
+    );
+    assert_eq!(c.pb, [Some(1), None, Some(3), Some(6)]);
+    assert_eq!(c.last_attempt, [Some(2), None, Some(4), Some(8)]);
+    assert_eq!(
+        c.average,
+        [Some((10, 5)), None, Some((20, 5)), Some((30, 5))]
+    );
+    // the segments on either side of X cover different stretches now
+    assert_eq!(c.best_segments, [Some(1), None, None, Some(3)]);
+}
+
+#[test]
+fn removing_a_split_drops_its_total() {
+    let mut c = counter();
+    c.migrate(
+        &ids(&["Start", "A", "B", "End"]),
+        &ids(&["Start", "B", "End"]),
+    );
+    assert_eq!(c.pb, [Some(3), Some(6)]);
+    assert_eq!(c.best_segments, [None, Some(3)]);
+}
+
+#[test]
+fn reordering_splits_moves_their_totals() {
+    let mut c = counter();
+    c.migrate(
+        &ids(&["Start", "A", "B", "End"]),
+        &ids(&["Start", "B", "A", "End"]),
+    );
+    assert_eq!(c.pb, [Some(3), Some(1), Some(6)]);
+    assert_eq!(c.best_segments, []);
+}
+
+#[test]
+fn repeated_keys_follow_their_count() {
+    let old = ids(&["Start", "Bell", "Bell", "End"]);
+
+    // another Bell at the end is Bell#2, so the first two keep their totals
+    let mut c = counter();
+    c.migrate(&old, &ids(&["Start", "Bell", "Bell", "Bell", "End"]));
+    assert_eq!(c.pb, [Some(1), Some(3), None, Some(6)]);
+    assert_eq!(c.best_segments, [Some(1), Some(2)]);
+
+    // taking away the first Bell makes the second one Bell#0
+    let mut c = counter();
+    c.migrate(&old, &ids(&["Start", "Bell", "End"]));
+    assert_eq!(c.pb, [Some(1), Some(6)]);
+    assert_eq!(c.best_segments, [Some(1)]);
+}
+
+#[test]
+fn unchanged_splits_keep_everything() {
+    let mut c = counter();
+    let same = ids(&["Start", "A", "B", "End"]);
+    c.migrate(&same, &same);
+    assert_eq!(c.pb, counter().pb);
+    assert_eq!(c.best_segments, counter().best_segments);
+}
@@ -0,0 +1,80 @@
+// cargo test --target $(rustc -vV | sed -n 's|host: ||p')
+// cargo test --target aarch64-apple-darwin
+// cargo test --target x86_64-apple-darwin
+
+//! Tests for the [AttemptHistory]: how an [Attempt] is saved as text and read back,
+//! and the counts of attempts reaching and reset in each segment.
+
+#![cfg(not(target_os = "unknown"))]
+
+use silksong_autosplit_wasm::history::{Attempt, AttemptHistory, MAX_ATTEMPTS};
+
+const SPLITS: &str = "2a553c61c1952e3a";
+
+fn attempt(splits: &str, reached: u64, ended: bool) -> Attempt {
+    Attempt {
+        splits: splits.to_string(),
+        reached,
+        ended,
+        hits: Vec::new(),
+        times: Vec::new(),
+    }
+}
+
+#[test]
+fn an_attempt_reads_back_the_way_it_was_saved() {
+    let a = Attempt {
+        splits: SPLITS.to_string(),
+        reached: 2,
+        ended: false,
+        hits: vec![0, 3],
+        times: vec![Some(12.5), None, Some(95.25)],
+    };
+    let s = a.to_string();
+    assert_eq!(
+        s,
+        "splits=2a553c61c1952e3a reached=2 ended=false hits=0,3 times=12.50,,95.25"
+    );
+    assert_eq!(Attempt::parse(&s), Some(a));
+
+    let empty = attempt(SPLITS, 0, true);
+    assert_eq!(Attempt::parse(&empty.to_string()), Some(empty));
+}
+
+#[test]
+fn an_attempt_that_cant_be_read_is_none() {
+    assert_eq!(Attempt::parse("splits=x reached=two"), None);
+    assert_eq!(Attempt::parse("splits=x hits=1,a"), None);
+    assert_eq!(Attempt::parse("splits=x color=red"), None);
+}
+
+#[test]
+fn reaching_and_resets_count_attempts_with_the_same_splits() {
+    let mut history = AttemptHistory::default();
+    history.push(attempt(SPLITS, 0, false));
+    history.push(attempt(SPLITS, 1, false));
+    history.push(attempt(SPLITS, 1, false));
+    history.push(attempt(SPLITS, 3, true));
+    history.push(attempt("other", 3, false));
+
+    assert_eq!(history.reaching(SPLITS, 0), 4);
+    assert_eq!(history.reaching(SPLITS, 1), 3);
+    assert_eq!(history.reaching(SPLITS, 2), 1);
+    assert_eq!(history.reaching(SPLITS, 3), 1);
+    assert_eq!(history.resets(SPLITS, 0), 1);
+    assert_eq!(history.resets(SPLITS, 1), 2);
+    assert_eq!(history.resets(SPLITS, 2), 0);
+    // reaching the last split isn't a reset
+    assert_eq!(history.resets(SPLITS, 3), 0);
+    assert_eq!(history.resets("other", 3), 1);
+}
+
+#[test]
+fn only_the_most_recent_attempts_are_kept() {
+    let mut history = AttemptHistory::default();
+    for reached in 0..(MAX_ATTEMPTS as u64 + 5) {
+        history.push(attempt(SPLITS, reached, false));
+    }
+    assert_eq!(history.attempts.len(), MAX_ATTEMPTS);
+    assert_eq!(history.attempts.front().map(|a| a.reached), Some(5));
+}
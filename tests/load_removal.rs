@@ -0,0 +1,206 @@
+// cargo test --target $(rustc -vV | sed -n 's|host: ||p')
+// cargo test --target aarch64-apple-darwin
+// cargo test --target x86_64-apple-darwin
+
+//! Tests for [pause_cause]: which cause wins when more than one applies,
+//! which causes each [LoadRemovalMode] removes, a small trace replayed through
+//! [simulate_load_removal], and how wide a [TraceRecorder] records fields.
+
+#![cfg(not(target_os = "unknown"))]
+
+use silksong_autosplit_wasm::{
+    load_removal::{
+        pause_cause, LoadRemovalInputs, LoadRemovalMode, LoadRemovalOptions, PauseCause,
+    },
+    replay::{simulate_load_removal, PausedInterval, Trace},
+    silksong_memory::{
+        Env, GameManagerPointers, PlayerDataPointers, GAME_STATE_LOADING, GAME_STATE_PLAYING,
+        UI_STATE_PAUSED, UI_STATE_PLAYING,
+    },
+    snapshot::Snapshot,
+    splits::{Split, SplitArgs},
+    trace::TraceRecorder,
+};
+
+/// Standard, with the pauses behind checkboxes turned on too.
+const EVERYTHING: LoadRemovalOptions = LoadRemovalOptions {
+    mode: LoadRemovalMode::Standard,
+    hazard_respawns: true,
+    fast_travel: true,
+    tilemap: true,
+};
+
+fn with_mode(mode: LoadRemovalMode) -> LoadRemovalOptions {
+    LoadRemovalOptions { mode, ..EVERYTHING }
+}
+
+/// Playing in a scene, with nothing paused.
+fn playing() -> LoadRemovalInputs {
+    LoadRemovalInputs {
+        game_state: GAME_STATE_PLAYING,
+        ui_state: UI_STATE_PLAYING,
+        accepting_input: true,
+        scene_load_null: true,
+        scene_name: "Bone_05".to_string(),
+        next_scene: "Bone_05".to_string(),
+        ..LoadRemovalInputs::default()
+    }
+}
+
+#[test]
+fn nothing_applies_while_playing() {
+    assert_eq!(pause_cause(&playing(), &EVERYTHING), None);
+}
+
+#[test]
+fn the_first_cause_in_order_wins() {
+    let inputs = LoadRemovalInputs {
+        game_state: GAME_STATE_LOADING,
+        look_for_teleporting: true,
+        ..playing()
+    };
+    assert_eq!(
+        pause_cause(&inputs, &EVERYTHING),
+        Some(PauseCause::LookForTeleporting)
+    );
+
+    let inputs = LoadRemovalInputs {
+        fast_travelling: true,
+        tilemap_rebuilding: true,
+        ..playing()
+    };
+    assert_eq!(
+        pause_cause(&inputs, &EVERYTHING),
+        Some(PauseCause::FastTravel)
+    );
+}
+
+#[test]
+fn a_cause_the_options_keep_falls_through_to_the_next() {
+    // the pause menu over a hazard respawn
+    let inputs = LoadRemovalInputs {
+        ui_state: UI_STATE_PAUSED,
+        hazard_respawning: true,
+        ..playing()
+    };
+    assert_eq!(
+        pause_cause(&inputs, &EVERYTHING),
+        Some(PauseCause::UiNotPlaying)
+    );
+    assert_eq!(
+        pause_cause(&inputs, &with_mode(LoadRemovalMode::StandardWithoutMenus)),
+        Some(PauseCause::HazardRespawn)
+    );
+    let no_hazard_respawns = LoadRemovalOptions {
+        hazard_respawns: false,
+        ..with_mode(LoadRemovalMode::StandardWithoutMenus)
+    };
+    assert_eq!(pause_cause(&inputs, &no_hazard_respawns), None);
+}
+
+#[test]
+fn the_checkbox_pauses_are_off_by_default() {
+    let inputs = LoadRemovalInputs {
+        hazard_respawning: true,
+        tilemap_rebuilding: true,
+        ..playing()
+    };
+    assert_eq!(pause_cause(&inputs, &LoadRemovalOptions::default()), None);
+}
+
+#[test]
+fn each_mode_removes_its_causes() {
+    use PauseCause::*;
+    let expected: [(LoadRemovalMode, &[PauseCause]); 4] = [
+        (LoadRemovalMode::Off, &[]),
+        (LoadRemovalMode::Standard, &PauseCause::ALL),
+        (
+            LoadRemovalMode::StandardWithoutMenus,
+            &[
+                LookForTeleporting,
+                NotAcceptingInput,
+                ExitingLevel,
+                Loading,
+                WaitingToEnterLevel,
+                SceneTransition,
+                HazardRespawn,
+                FastTravel,
+                TilemapRebuilding,
+            ],
+        ),
+        (
+            LoadRemovalMode::StrictTransitionsOnly,
+            &[ExitingLevel, Loading, WaitingToEnterLevel, SceneTransition],
+        ),
+    ];
+    for (mode, causes) in expected {
+        let removed: Vec<PauseCause> = PauseCause::ALL
+            .into_iter()
+            .filter(|&c| with_mode(mode).removes(c))
+            .collect();
+        assert_eq!(removed, causes, "{:?}", mode);
+    }
+}
+
+/// Starts a new game, loads for 10 ticks, and then opens the pause menu for 20 ticks,
+/// before killing Moss Mother and reaching the ending.
+const TRACE: &str = r#"
+trace header version=2 splits=StartNewGame,MossMother,EndingSplit
+trace 0 scene_load=0000000000000000 game_state=0400000000000000 scene_name="Opening_Sequence" next_scene_name="Opening_Sequence" ui_state_vanilla=0400000000000000 accepting_input=0100000000000000
+trace 10 scene_name="Tut_01" next_scene_name="Tut_01"
+trace 60 game_state=0200000000000000
+trace 70 game_state=0400000000000000
+trace 80 ui_state_vanilla=0500000000000000
+trace 100 ui_state_vanilla=0400000000000000
+trace 150 defeated_moss_mother=0100000000000000
+trace 200 next_scene_name="Cinematic_Ending_A" scene_load=0000000000000000
+"#;
+
+#[test]
+fn simulating_a_trace_reports_the_removed_time() {
+    let mut trace = Trace::parse(TRACE).unwrap();
+    let report = simulate_load_removal(&trace);
+    assert_eq!(
+        report.paused,
+        [
+            PausedInterval {
+                start: 60,
+                end: 70,
+                cause: PauseCause::Loading,
+            },
+            PausedInterval {
+                start: 80,
+                end: 100,
+                cause: PauseCause::UiNotPlaying,
+            },
+        ]
+    );
+    assert_eq!(report.real_ticks, 190);
+    assert_eq!(report.game_ticks, 160);
+
+    trace.header.load_removal.mode = LoadRemovalMode::StandardWithoutMenus;
+    let report = simulate_load_removal(&trace);
+    assert_eq!(report.paused.len(), 1);
+    assert_eq!(report.game_ticks, 180);
+}
+
+#[test]
+fn recording_writes_each_field_as_wide_as_its_type() {
+    let gm = GameManagerPointers::new();
+    let pd = PlayerDataPointers::new();
+    let mut snapshot = Snapshot::new();
+    // as wide as version 1 traces recorded every field
+    snapshot.set("game_state", GAME_STATE_PLAYING as u64);
+    snapshot.set("defeated_moss_mother", 1u64);
+    snapshot.set("hasDash", 1u64);
+    let args = SplitArgs {
+        field: "hasDash".to_string(),
+        ..SplitArgs::default()
+    };
+    let mut recorder = TraceRecorder::new().with_split_args(&[Split::PlayerDataBool], &[args]);
+    let line = recorder.record(&Env::new(&snapshot, &pd, &gm)).unwrap();
+    assert_eq!(
+        line,
+        "trace 0 game_state=04000000 defeated_moss_mother=01 hasDash=01"
+    );
+}
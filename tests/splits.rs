@@ -0,0 +1,1153 @@
+// cargo test --target $(rustc -vV | sed -n 's|host: ||p')
+// cargo test --target aarch64-apple-darwin
+// cargo test --target x86_64-apple-darwin
+
+//! Golden tests for every [Split]: each one is driven through [splits] with a scripted
+//! sequence of scenes and PlayerData values, and has to split at the tick it's expected to,
+//! without splitting again, resetting, or skipping before the script ends.
+//!
+//! [case] matches on every variant without a catch-all,
+//! so a new split doesn't compile until it says how it should be tested.
+
+#![cfg(not(target_os = "unknown"))]
+
+use silksong_autosplit_wasm::{
+    silksong_memory::{
+        Env, GameManagerPointers, PlayerDataPointers, QuestCompletion, SceneStore,
+        GAME_STATE_PLAYING, MENU_TITLE,
+    },
+    snapshot::Snapshot,
+    splits::{splits, Split, SplitArgs},
+    store::Store,
+    timer::SplitterAction,
+};
+use ugly_widget::radio_button::{options_str, RadioButtonOptions};
+
+/// The tick that each scripted change happens on
+const CHANGE_TICK: u64 = 10;
+/// The tick of the transition after the change, for splits that wait for one
+const TRANSITION_TICK: u64 = 20;
+const LAST_TICK: u64 = 30;
+
+/// The scene to start in, for scripts that aren't about a particular transition
+const START_SCENE: &str = "Bone_05";
+const NEXT_SCENE: &str = "Bone_04";
+
+#[derive(Clone, Copy, Debug)]
+enum Step {
+    Bool(&'static str, bool),
+    Int(&'static str, i32),
+    U64(&'static str, u64),
+    Str(&'static str, &'static str),
+    Tool(&'static str),
+    Relic(&'static str),
+    /// Sets the SceneData bool with the id in the scene
+    SceneBool(&'static str, &'static str, bool),
+    /// Sets the SceneData int with the id in the scene
+    SceneInt(&'static str, &'static str, i32),
+    /// Sets how far along the quest with the id is
+    Quest(&'static str, QuestCompletion),
+    /// Sets the gate that the next scene is entered through
+    Gate(&'static str),
+    /// Loads into the scene, so that the scene name and next scene name both become it
+    Scene(&'static str),
+}
+
+impl Step {
+    fn apply(&self, snapshot: &mut Snapshot) {
+        match *self {
+            Step::Bool(name, b) => snapshot.set(name, b),
+            Step::Int(name, n) => snapshot.set(name, n),
+            Step::U64(name, n) => snapshot.set(name, n),
+            Step::Str(name, s) => snapshot.set_string(name, s),
+            Step::Tool(tool) => snapshot.add_tool(tool),
+            Step::Relic(relic) => snapshot.collect_relic(relic),
+            Step::SceneBool(scene, id, b) => snapshot.set_scene_bool(scene, id, b),
+            Step::SceneInt(scene, id, n) => snapshot.set_scene_int(scene, id, n),
+            Step::Quest(quest, completion) => snapshot.set_quest(quest, completion),
+            Step::Gate(gate) => snapshot.set_string("entry_gate_name", gate),
+            Step::Scene(scene) => {
+                snapshot.set_string("scene_name", scene);
+                snapshot.set_string("next_scene_name", scene);
+            }
+        }
+    }
+}
+
+/// The steps to take on each tick, and the only action expected from the split.
+struct Script {
+    steps: Vec<(u64, Step)>,
+    expected: (u64, SplitterAction),
+    /// The text settings in the split's row
+    args: SplitArgs,
+}
+
+impl Script {
+    /// Starts out playing in `scene`, with nothing else known about the save file.
+    fn new(scene: &'static str) -> Script {
+        Script {
+            steps: vec![
+                (0, Step::Int("game_state", GAME_STATE_PLAYING)),
+                // a null scene_load, so that next_scene_name gets read
+                (0, Step::U64("scene_load", 0)),
+                (0, Step::Bool("disable_pause", false)),
+                (0, Step::Int("tools_version", 0)),
+                (0, Step::Int("relics_version", 0)),
+                (0, Step::Scene(scene)),
+            ],
+            expected: (CHANGE_TICK, SplitterAction::Split),
+            args: SplitArgs::default(),
+        }
+    }
+
+    fn step(mut self, tick: u64, step: Step) -> Script {
+        self.steps.push((tick, step));
+        self
+    }
+
+    fn expect(mut self, tick: u64, action: SplitterAction) -> Script {
+        self.expected = (tick, action);
+        self
+    }
+
+    fn arg(mut self, name: &str, value: &str) -> Script {
+        assert!(
+            self.args.set(name, value.to_string()),
+            "no split arg {}",
+            name
+        );
+        self
+    }
+}
+
+/// How a split's condition becomes true, on the [CHANGE_TICK] unless it says otherwise.
+#[derive(Debug)]
+enum Case {
+    /// Produces [SplitterAction::ManualSplit] right away
+    Manual,
+    /// A PlayerData bool goes from false to true
+    Bool(&'static str),
+    /// A PlayerData bool goes from false to true, and then the split waits for the
+    /// transition on the [TRANSITION_TICK]
+    BoolTrans(&'static str),
+    /// All but the last of the PlayerData bools start out true, and then the last one does too
+    AllBools(&'static [&'static str]),
+    /// A PlayerData int goes from the first value to the second
+    Int(&'static str, i32, i32),
+    /// Several PlayerData ints each go from their first value to their second
+    Ints(&'static [(&'static str, i32, i32)]),
+    /// A PlayerData string goes from the first value to the second
+    Str(&'static str, &'static str, &'static str),
+    /// A tool gets added to the tool list
+    Tool(&'static str),
+    /// A transition from the first scene to the second
+    Transition(&'static str, &'static str),
+    /// A transition from the first scene to the second, entering through the gate
+    GateTransition(&'static str, &'static str, &'static str),
+    /// Quits to the main menu from the scene, with it as the respawn scene
+    QuitToMenu(&'static str),
+    /// Each case separately, for splits with more than one way to happen
+    Cases(&'static [Case]),
+    /// Anything else
+    Custom(fn() -> Script),
+}
+
+impl Case {
+    fn leaves(&self) -> Vec<&Case> {
+        match self {
+            Case::Cases(cases) => cases.iter().flat_map(Case::leaves).collect(),
+            case => vec![case],
+        }
+    }
+
+    fn script(&self) -> Script {
+        match *self {
+            Case::Manual => Script::new(START_SCENE).expect(0, SplitterAction::ManualSplit),
+            Case::Bool(name) => Script::new(START_SCENE)
+                .step(0, Step::Bool(name, false))
+                .step(CHANGE_TICK, Step::Bool(name, true)),
+            Case::BoolTrans(name) => Case::Bool(name)
+                .script()
+                .step(TRANSITION_TICK, Step::Scene(NEXT_SCENE))
+                .expect(TRANSITION_TICK, SplitterAction::Split),
+            Case::AllBools(names) => {
+                let (last, rest) = names.split_last().unwrap();
+                rest.iter().fold(Case::Bool(last).script(), |s, name| {
+                    s.step(0, Step::Bool(name, true))
+                })
+            }
+            Case::Int(name, old, new) => Script::new(START_SCENE)
+                .step(0, Step::Int(name, old))
+                .step(CHANGE_TICK, Step::Int(name, new)),
+            Case::Ints(ints) => {
+                ints.iter()
+                    .fold(Script::new(START_SCENE), |s, &(name, old, new)| {
+                        s.step(0, Step::Int(name, old))
+                            .step(CHANGE_TICK, Step::Int(name, new))
+                    })
+            }
+            Case::Str(name, old, new) => Script::new(START_SCENE)
+                .step(0, Step::Str(name, old))
+                .step(CHANGE_TICK, Step::Str(name, new)),
+            Case::Tool(tool) => Script::new(START_SCENE).step(CHANGE_TICK, Step::Tool(tool)),
+            Case::Transition(old, new) => Script::new(old).step(CHANGE_TICK, Step::Scene(new)),
+            Case::GateTransition(old, new, gate) => Script::new(old)
+                .step(CHANGE_TICK, Step::Gate(gate))
+                .step(CHANGE_TICK, Step::Scene(new)),
+            Case::QuitToMenu(scene) => Script::new(scene)
+                .step(0, Step::Str("respawn_scene", scene))
+                .step(CHANGE_TICK, Step::Scene(MENU_TITLE)),
+            Case::Cases(_) => unreachable!("Cases are split into their leaves"),
+            Case::Custom(script) => script(),
+        }
+    }
+}
+
+/// Runs the `script` through [splits] the way the auto splitter does on every tick,
+/// up until the first action other than [SplitterAction::Pass],
+/// since that's where the auto splitter would move on from this split.
+/// Steps through the whole script, producing each tick where the split starts to produce an
+/// action other than [SplitterAction::Pass], along with the action.
+///
+/// Most splits keep splitting for as long as their condition holds, which the timer never
+/// sees since it moves on to the next split, so an action repeated on the following ticks
+/// doesn't count again. A split that goes back to passing and then splits again, or that
+/// resets or skips anywhere in the script, shows up as another action.
+fn run(split: &Split, script: &Script) -> Vec<(u64, SplitterAction)> {
+    let mut actions = Vec::new();
+    let mut last_action = SplitterAction::Pass;
+    let gm = GameManagerPointers::new();
+    let pd = PlayerDataPointers::new();
+    let mut snapshot = Snapshot::new();
+    let mut scene_store = SceneStore::new();
+    let mut store = Store::new();
+    for tick in 0..=LAST_TICK {
+        for (_, step) in script.steps.iter().filter(|(t, _)| *t == tick) {
+            step.apply(&mut snapshot);
+        }
+        let env = Env::new(&snapshot, &pd, &gm);
+        store.update_all(Some(&env));
+        let trans_now = scene_store.transition_now(&env);
+        let action = splits(
+            split,
+            Some(&script.args),
+            &env,
+            trans_now,
+            &mut scene_store,
+            &mut store,
+        );
+        if action != SplitterAction::Pass && action != last_action {
+            actions.push((tick, action.clone()));
+        }
+        last_action = action;
+    }
+    actions
+}
+
+#[test]
+fn every_split_splits_at_the_expected_tick() {
+    let mut failures = Vec::new();
+    for split in Split::radio_button_options().into_iter().map(|o| o.value) {
+        for leaf in case(&split).leaves() {
+            let script = leaf.script();
+            let actions = run(&split, &script);
+            if actions[..] != [script.expected.clone()] {
+                failures.push(format!(
+                    "{} with {:?}: expected only {:?}, got {:?}",
+                    options_str(&split),
+                    leaf,
+                    script.expected,
+                    actions
+                ));
+            }
+        }
+    }
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
+
+fn keys(keys: &[&str]) -> Vec<String> {
+    keys.iter().map(|k| k.to_string()).collect()
+}
+
+fn scene_args(scene: &str) -> SplitArgs {
+    SplitArgs {
+        scene: scene.to_string(),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn split_args_follow_their_row() {
+    let old = keys(&["StartNewGame", "EnterScene", "PlayerDataBool", "EnterScene"]);
+    let args = [
+        SplitArgs::default(),
+        scene_args("Bone_04"),
+        SplitArgs {
+            field: "hasDash".to_string(),
+            ..Default::default()
+        },
+        scene_args("Hang_17b"),
+    ];
+    // insert a row before the PlayerData split, and remove the first row, both at once
+    let new = keys(&["EnterScene", "Bell", "PlayerDataBool", "EnterScene"]);
+    let migrated = SplitArgs::migrate(&args, &old, &new);
+    assert_eq!(
+        migrated,
+        [
+            scene_args("Bone_04"),
+            SplitArgs::default(),
+            args[2].clone(),
+            scene_args("Hang_17b")
+        ]
+    );
+    // reorder, where rows with the same key keep their args in order
+    let new = keys(&["PlayerDataBool", "EnterScene", "EnterScene"]);
+    let migrated = SplitArgs::migrate(&args, &old, &new);
+    assert_eq!(
+        migrated,
+        [
+            args[2].clone(),
+            scene_args("Bone_04"),
+            scene_args("Hang_17b")
+        ]
+    );
+    // move the PlayerData split up
+    let new = keys(&["StartNewGame", "PlayerDataBool", "EnterScene", "EnterScene"]);
+    let migrated = SplitArgs::migrate(&args, &old, &new);
+    assert_eq!(
+        migrated,
+        [
+            SplitArgs::default(),
+            args[2].clone(),
+            scene_args("Bone_04"),
+            scene_args("Hang_17b")
+        ]
+    );
+}
+
+#[test]
+fn split_args_stay_with_their_row_when_a_split_with_the_same_key_is_inserted_above() {
+    let old = keys(&["StartNewGame", "EnterScene"]);
+    let args = vec![SplitArgs::default(), scene_args("Bone_04")];
+    // the list inserts a Manual Split, which is then changed to another Enter Scene
+    let inserted = keys(&["StartNewGame", "ManualSplit", "EnterScene"]);
+    let args = SplitArgs::migrate(&args, &old, &inserted);
+    let changed = keys(&["StartNewGame", "EnterScene", "EnterScene"]);
+    let args = SplitArgs::migrate(&args, &inserted, &changed);
+    assert_eq!(
+        args,
+        [
+            SplitArgs::default(),
+            SplitArgs::default(),
+            scene_args("Bone_04")
+        ]
+    );
+}
+
+/// The Verdania orbs: 7 single orbs, and bit fields for the rest,
+/// with the 12th orb collected on the [CHANGE_TICK].
+fn verdania_orbs() -> Script {
+    let singles = [
+        "orbs_02c",
+        "orbs_03",
+        "orbs_06",
+        "orbs_11",
+        "orbs_16_b",
+        "orbs_16_c",
+        "orbs_21",
+    ];
+    singles
+        .into_iter()
+        .fold(Script::new(START_SCENE), |s, name| {
+            s.step(0, Step::Bool(name, true))
+        })
+        .step(0, Step::U64("orbs_18_a", 0b1111))
+        .step(CHANGE_TICK, Step::U64("orbs_18_a", 0b11111))
+}
+
+/// Enters the scene from the `scene` setting, with nothing else set.
+fn enter_scene() -> Script {
+    Case::Transition("Hang_08", "Hang_17b")
+        .script()
+        .arg("scene", "Hang_17b")
+}
+
+/// Enters the scene from the `scene` setting, from the `previous_scene` through the `entry_gate`,
+/// after first entering it from somewhere else.
+fn enter_scene_from_through_gate() -> Script {
+    Script::new("Hang_06")
+        .step(CHANGE_TICK, Step::Gate("left1"))
+        .step(CHANGE_TICK, Step::Scene("Hang_17b"))
+        .step(TRANSITION_TICK - 2, Step::Scene("Hang_08"))
+        .step(TRANSITION_TICK, Step::Gate("right1"))
+        .step(TRANSITION_TICK, Step::Scene("Hang_17b"))
+        .expect(TRANSITION_TICK, SplitterAction::Split)
+        .arg("scene", "Hang_17b")
+        .arg("previous_scene", "Hang_08")
+        .arg("entry_gate", "right1")
+}
+
+/// A PlayerData bool named in the `field` setting goes from false to true.
+fn player_data_bool() -> Script {
+    Case::Bool("hasDash").script().arg("field", "hasDash")
+}
+
+/// A PlayerData int named in the `field` setting becomes equal to the `value`.
+fn player_data_int_equal() -> Script {
+    Case::Int("silkMax", 9, 10)
+        .script()
+        .arg("field", "silkMax")
+        .arg("comparison", "==")
+        .arg("value", "10")
+}
+
+/// A PlayerData int named in the `field` setting goes past the `value`.
+fn player_data_int_at_least() -> Script {
+    Case::Int("silkMax", 9, 12)
+        .script()
+        .arg("field", "silkMax")
+        .arg("comparison", ">=")
+        .arg("value", "10")
+}
+
+/// A PlayerData int named in the `field` setting changes at all.
+fn player_data_int_changed() -> Script {
+    Case::Int("silkMax", 9, 10)
+        .script()
+        .arg("field", "silkMax")
+        .arg("comparison", "changed")
+}
+
+/// A SceneData bool with the `id` setting in the `scene` setting gets saved as true,
+/// where the game hadn't saved anything for it before.
+fn scene_data_bool() -> Script {
+    Script::new(START_SCENE)
+        .step(0, Step::SceneBool("Bone_05", "Wall", false))
+        .step(CHANGE_TICK, Step::SceneBool("Bone_04", "Lever", true))
+        .arg("scene", "Bone_04")
+        .arg("id", "Lever")
+}
+
+/// A SceneData int with the `id` setting in the `scene` setting goes past the `value`.
+fn scene_data_int() -> Script {
+    Script::new(START_SCENE)
+        .step(0, Step::SceneInt("Bone_04", "Gate Switch", 1))
+        .step(CHANGE_TICK, Step::SceneInt("Bone_04", "Gate Switch", 3))
+        .arg("scene", "Bone_04")
+        .arg("id", "Gate Switch")
+        .arg("comparison", ">=")
+        .arg("value", "2")
+}
+
+/// The quest with the id in the `quest` setting gets accepted.
+fn quest_accepted() -> Script {
+    let accepted = QuestCompletion {
+        accepted: true,
+        ..QuestCompletion::default()
+    };
+    Script::new(START_SCENE)
+        .step(CHANGE_TICK, Step::Quest("Rock Rollers", accepted))
+        .arg("quest", "Rock Rollers")
+}
+
+/// The count toward the objective of the quest in the `quest` setting reaches the `count`.
+fn quest_count() -> Script {
+    let count = |completed_count| QuestCompletion {
+        accepted: true,
+        completed_count,
+        completed: false,
+    };
+    Script::new(START_SCENE)
+        .step(0, Step::Quest("Rock Rollers", count(2)))
+        .step(CHANGE_TICK, Step::Quest("Rock Rollers", count(3)))
+        .arg("quest", "Rock Rollers")
+        .arg("count", "3")
+}
+
+/// The quest with the id in the `quest` setting gets completed, after being accepted.
+fn quest_completed() -> Script {
+    let accepted = QuestCompletion {
+        accepted: true,
+        ..QuestCompletion::default()
+    };
+    let completed = QuestCompletion {
+        completed: true,
+        ..accepted
+    };
+    Script::new(START_SCENE)
+        .step(0, Step::Quest("Rock Rollers", accepted))
+        .step(CHANGE_TICK, Step::Quest("Rock Rollers", completed))
+        .arg("quest", "Rock Rollers")
+}
+
+/// The tool with the id in the `tool` setting gets added to the tool list.
+fn tool_acquired() -> Script {
+    Case::Tool("Tri Pin").script().arg("tool", "Tri Pin")
+}
+
+/// The second of the `conditions` splits, without the first.
+fn any_of() -> Script {
+    Case::Bool("defeated_bell_beast")
+        .script()
+        .step(0, Step::Bool("defeated_moss_mother", false))
+        .arg("conditions", "MossMother,BellBeast")
+}
+
+/// The second of the `conditions` splits, and then the first.
+fn all_of() -> Script {
+    Case::Bool("defeated_moss_mother")
+        .script()
+        .step(0, Step::Bool("defeated_bell_beast", false))
+        .step(CHANGE_TICK - 5, Step::Bool("defeated_bell_beast", true))
+        .arg("conditions", "MossMother,BellBeast")
+}
+
+/// Two PlayerData splits, each with its own `field` setting in the `conditions`,
+/// the second and then the first.
+fn all_of_with_settings() -> Script {
+    Case::Bool("hasDash")
+        .script()
+        .step(0, Step::Bool("hasBrolly", false))
+        .step(CHANGE_TICK - 5, Step::Bool("hasBrolly", true))
+        .arg(
+            "conditions",
+            "PlayerDataBool(field=hasDash), PlayerDataBool(field = hasBrolly)",
+        )
+}
+
+/// A boss flag and then a transition, the same as [Split::MossMotherTrans].
+fn sequence() -> Script {
+    Case::BoolTrans("defeated_moss_mother")
+        .script()
+        .arg("conditions", "MossMother, AnyTransition")
+}
+
+/// The second of the `conditions` splits was already true before the first,
+/// so it only counts once it becomes true again afterwards.
+fn sequence_already_true() -> Script {
+    Script::new(START_SCENE)
+        .step(0, Step::Bool("defeated_bell_beast", true))
+        .step(0, Step::Bool("defeated_moss_mother", false))
+        .step(CHANGE_TICK - 5, Step::Bool("defeated_moss_mother", true))
+        .step(CHANGE_TICK - 3, Step::Bool("defeated_bell_beast", false))
+        .step(CHANGE_TICK, Step::Bool("defeated_bell_beast", true))
+        .arg("conditions", "MossMother,BellBeast")
+}
+
+/// Both of the `conditions` splits are already true on the first tick that the split is checked,
+/// when nothing has been checked before, so the second one counts as starting then too.
+fn sequence_on_the_first_tick() -> Script {
+    Script::new(START_SCENE)
+        .step(0, Step::Bool("defeated_moss_mother", true))
+        .step(0, Step::Bool("defeated_bell_beast", true))
+        .arg("conditions", "MossMother,BellBeast")
+        .expect(0, SplitterAction::Split)
+}
+
+/// Sitting at a bench while the respawn scene is the one in the `respawn_scene` setting,
+/// after sitting at a bench in another one.
+fn all_of_in_respawn_scene() -> Script {
+    Script::new(START_SCENE)
+        .step(0, Step::Bool("at_bench", false))
+        .step(0, Step::Str("respawn_scene", NEXT_SCENE))
+        .step(CHANGE_TICK - 6, Step::Bool("at_bench", true))
+        .step(CHANGE_TICK - 4, Step::Bool("at_bench", false))
+        .step(CHANGE_TICK, Step::Str("respawn_scene", START_SCENE))
+        .step(CHANGE_TICK, Step::Bool("at_bench", true))
+        .arg("conditions", "BenchAny")
+        .arg("respawn_scene", START_SCENE)
+}
+
+/// The third flea rescued, with a `count` of 3.
+fn fleas_rescued() -> Script {
+    Case::Bool("savedflea_coral_24")
+        .script()
+        .step(0, Step::Bool("savedflea_ant_03", true))
+        .step(0, Step::Bool("tamed_giant_flea", true))
+        .arg("count", "3")
+}
+
+/// The third relic collected, with a `count` of 3.
+fn relics_collected() -> Script {
+    Script::new(START_SCENE)
+        .step(0, Step::Relic("Seal Chit Aspid"))
+        .step(0, Step::Relic("Weaver Record"))
+        .step(CHANGE_TICK, Step::Relic("Bone Record Greymoor"))
+        .arg("count", "3")
+}
+
+/// The 6th mask shard, after the first mask upgrade, with a `count` of 6.
+fn mask_shards_total() -> Script {
+    Case::Int("heart_pieces", 1, 2)
+        .script()
+        .step(0, Step::Int("max_health_base", 6))
+        .arg("count", "6")
+}
+
+/// The 4th spool fragment, completing the second spool upgrade, with a `count` of 4.
+fn spool_fragments_total() -> Script {
+    Case::Ints(&[("silk_max", 10, 11), ("silk_spool_parts", 1, 0)])
+        .script()
+        .arg("count", "4")
+}
+
+fn case(split: &Split) -> Case {
+    use Case::*;
+    match split {
+        Split::ManualSplit => Manual,
+        Split::StartNewGame => Transition("Opening_Sequence", "Tut_01"),
+        Split::Act1Start => Cases(&[
+            Transition("Opening_Sequence", "Tut_01"),
+            GateTransition("Menu_Title", "Tut_01", "Death Respawn Marker Init"),
+        ]),
+        Split::EndingSplit => Cases(&[
+            Transition("Song_Tower_01", "Cinematic_Ending_A"),
+            Transition("Abyss_Cocoon", "Cinematic_Ending_C"),
+        ]),
+        Split::EndingA => Transition("Song_Tower_01", "Cinematic_Ending_A"),
+        Split::Menu => Transition("Bone_05", "Menu_Title"),
+        Split::BenchAny => Bool("at_bench"),
+        Split::PlayerDeath => Int("health", 1, 0),
+        Split::PlayerDataBool => Custom(player_data_bool),
+        Split::PlayerDataInt => Cases(&[
+            Custom(player_data_int_equal),
+            Custom(player_data_int_at_least),
+            Custom(player_data_int_changed),
+        ]),
+        Split::SceneDataBool => Custom(scene_data_bool),
+        Split::SceneDataInt => Custom(scene_data_int),
+        Split::QuestAccepted => Custom(quest_accepted),
+        Split::QuestCount => Custom(quest_count),
+        Split::QuestCompleted => Custom(quest_completed),
+        Split::AnyOf => Custom(any_of),
+        Split::AllOf => Cases(&[
+            Custom(all_of),
+            Custom(all_of_in_respawn_scene),
+            Custom(all_of_with_settings),
+        ]),
+        Split::Sequence => Cases(&[
+            Custom(sequence),
+            Custom(sequence_already_true),
+            Custom(sequence_on_the_first_tick),
+        ]),
+        Split::AnyTransition => Transition("Tut_01", "Tut_02"),
+        Split::TransitionExcludingDiscontinuities => Transition("Tut_01", "Tut_02"),
+        Split::EnterScene => Cases(&[Custom(enter_scene), Custom(enter_scene_from_through_gate)]),
+        Split::MossMother => Bool("defeated_moss_mother"),
+        Split::MossMotherTrans => BoolTrans("defeated_moss_mother"),
+        Split::EnterBoneBottom => Transition("Bone_01", "Bonetown"),
+        Split::EnterMosshome => Transition("Bone_05", "Mosstown_01"),
+        Split::SilkSpear => Bool("has_needle_throw"),
+        Split::SilkSpearTrans => BoolTrans("has_needle_throw"),
+        Split::BoneBottomSimpleKey => Bool("has_bonebottom_simple_key"),
+        Split::EnterWeavenestAtla => Transition("Tut_01b", "Weave_04"),
+        Split::BellBeast => Bool("defeated_bell_beast"),
+        Split::BellBeastTrans => BoolTrans("defeated_bell_beast"),
+        Split::MarrowBell => Bool("bell_shrine_bone_forest"),
+        Split::SwiftStep => Bool("has_dash"),
+        Split::SwiftStepTrans => BoolTrans("has_dash"),
+        Split::Lace1 => Bool("defeated_lace1"),
+        Split::Lace1Trans => BoolTrans("defeated_lace1"),
+        Split::DeepDocksBell => Bool("bell_shrine_wilds"),
+        Split::EnterWormways => Cases(&[
+            Transition("Crawl_02", "Crawl_03b"),
+            Transition("Aspid_01", "Crawl_01"),
+        ]),
+        Split::EnterUpperWormways => Transition("Crawl_03b", "Crawl_03"),
+        Split::Sharpdart => Bool("has_silk_charge"),
+        Split::SharpdartTrans => BoolTrans("has_silk_charge"),
+        Split::PlasmifiedZangoEncountered => Bool("encountered_plasmified_zango"),
+        Split::EnterHuntersMarch => Cases(&[
+            Transition("Ant_02", "Ant_03"),
+            Transition("Ant_05b", "Ant_14"),
+        ]),
+        Split::HuntersMarchPostMiddleArenaTransition => Transition("Ant_04_mid", "Ant_04"),
+        Split::EnterFarFields => Transition("Dock_01", "Bone_East_01"),
+        Split::DriftersCloak => Bool("has_brolly"),
+        Split::DriftersCloakTrans => BoolTrans("has_brolly"),
+        Split::FourthChorus => Bool("defeated_song_golem"),
+        Split::GurrTheOutcastEncountered => Bool("encountered_ant_trapper"),
+        Split::GurrTheOutcast => Bool("defeated_ant_trapper"),
+        Split::EnterGreymoor => Transition("Bone_East_14", "Greymoor_01"),
+        Split::GreymoorBell => Bool("bell_shrine_greymoor"),
+        Split::Moorwing => Bool("defeated_vampire_gnat_boss"),
+        Split::MoorwingTrans => BoolTrans("defeated_vampire_gnat_boss"),
+        Split::ThreadStorm => Bool("has_thread_sphere"),
+        Split::ThreadStormTrans => BoolTrans("has_thread_sphere"),
+        Split::EnterHalfwayHomeBasement => Transition("Halfway_01", "Ant_08"),
+        Split::YarnabySlap => Int("belltown_doctor_convo", 2, 3),
+        Split::Crawfather => Bool("defeated_crawfather"),
+        Split::EnterWispThicket => Transition("Greymoor_06", "Wisp_04"),
+        Split::EnterFatherOfTheFlame => Transition("Wisp_02", "Belltown_08"),
+        Split::FatherOfTheFlame => Bool("defeated_wisp_pyre_effigy"),
+        Split::EnterShellwood => Transition("Belltown_07", "Shellwood_01"),
+        Split::SisterSplinterEncountered => Bool("encountered_sister_splinter"),
+        Split::SisterSplinter => Bool("defeated_sister_splinter"),
+        Split::SisterSplinterTrans => BoolTrans("defeated_sister_splinter"),
+        Split::ClingGrip => Bool("has_wall_jump"),
+        Split::ClingGripTrans => BoolTrans("has_wall_jump"),
+        Split::ShellwoodBell => Bool("bell_shrine_shellwood"),
+        Split::EnterBellhart => Cases(&[
+            Transition("Belltown_06", "Belltown"),
+            Transition("Belltown_07", "Belltown"),
+            Transition("Belltown_basement", "Belltown"),
+        ]),
+        Split::WidowEncountered => Bool("encountered_spinner"),
+        Split::Widow => Bool("spinner_defeated"),
+        Split::MenuNeedolinMemory => QuitToMenu("Belltown_Shrine"),
+        Split::BellhartBell => Bool("bell_shrine_bellhart"),
+        Split::EnterBlastedSteps => Transition("Coral_19", "Coral_02"),
+        Split::GreatConchflies => Bool("defeated_coral_drillers"),
+        Split::GreatConchfliesTrans => BoolTrans("defeated_coral_drillers"),
+        Split::NeedleStrike => Bool("has_charge_slash"),
+        Split::NeedleStrikeTrans => BoolTrans("has_charge_slash"),
+        Split::EnterLastJudge => Transition("Coral_32", "Coral_Judge_Arena"),
+        Split::LastJudgeEncountered => Bool("encountered_last_judge"),
+        Split::LastJudge => Bool("defeated_last_judge"),
+        Split::LostGarmond => Bool("defeated_lost_garmond"),
+        Split::EnterSinnersRoad => Cases(&[
+            Transition("Greymoor_03", "Dust_01"),
+            Transition("Dust_Maze_08_completed", "Dust_05"),
+            Transition("Shadow_05", "Dust_06"),
+        ]),
+        Split::SinnersRoadSimpleKey => Bool("collected_dust_cage_key"),
+        Split::Lugoli => Bool("defeated_roach_keeper_chef"),
+        Split::EnterMist => Cases(&[
+            Transition("Dust_05", "Dust_Maze_09_entrance"),
+            Transition("Shadow_04", "Dust_Maze_09_entrance"),
+        ]),
+        Split::MistCrossing => Transition("Dust_Maze_01", "Dust_Maze_crossing"),
+        Split::LeaveMist => Transition("Dust_Maze_Last_Hall", "Dust_09"),
+        Split::EnterBilewater => Cases(&[
+            Transition("Dust_06", "Shadow_05"),
+            Transition("Library_07", "Shadow_22"),
+            Transition("Dust_09", "Shadow_04"),
+            Transition("Bellway_Shadow", "Shadow_04"),
+            Transition("Aqueduct_04", "Shadow_01"),
+        ]),
+        Split::EnterExhaustOrgan => Transition("Dust_09", "Organ_01"),
+        Split::Phantom => Bool("defeated_phantom"),
+        Split::PhantomTrans => BoolTrans("defeated_phantom"),
+        Split::CrossStitch => Bool("has_parry"),
+        Split::CrossStitchTrans => BoolTrans("has_parry"),
+        Split::TrailsEndTrans => Transition("Shadow_24", "Shadow_19"),
+        Split::EnterTheSlab => Transition("Slab_01", "Slab_02"),
+        Split::WardenflyCaptureTrans => GateTransition("Slab_02", "Slab_03", "door_slabCaged"),
+        Split::SlabKeyIndolent => Bool("has_slab_key_a"),
+        Split::SlabKeyHeretic => Bool("has_slab_key_b"),
+        Split::SlabKeyApostate => Bool("has_slab_key_c"),
+        Split::Broodmother => Bool("defeated_brood_mother"),
+        Split::EnterFirstSinner => Transition("Slab_10c", "Slab_10b"),
+        Split::FirstSinnerEncountered => Bool("encountered_first_weaver"),
+        Split::FirstSinner => Bool("defeated_first_weaver"),
+        Split::RuneRage => Bool("has_silk_bomb"),
+        Split::RuneRageTrans => BoolTrans("has_silk_bomb"),
+        Split::EnterMountFay => Transition("Slab_06", "Peak_01"),
+        Split::EnterBrightvein => Transition("Peak_06b", "Peak_06"),
+        Split::UpperMountFayTrans => Transition("Peak_01", "Peak_07"),
+        Split::FaydownCloak => Bool("has_double_jump"),
+        Split::FaydownCloakTrans => BoolTrans("has_double_jump"),
+        Split::EnterSandsOfKarak => Cases(&[
+            Transition("Coral_25", "Coral_23"),
+            Transition("Coral_35", "Coral_35b"),
+            Transition("Coral_38", "Coral_26"),
+        ]),
+        Split::EnterVoltnest => Transition("Coral_35b", "Coral_29"),
+        Split::Voltvyrm => Bool("defeated_zap_core_enemy"),
+        Split::RagingConchfly => Bool("defeated_coral_driller_solo"),
+        Split::RagingConchflyTrans => BoolTrans("defeated_coral_driller_solo"),
+        Split::WatcherAtTheEdge => Bool("defeated_grey_warrior"),
+        Split::Act2Started => Bool("act2_started"),
+        Split::EnterCogworkDancers => Cases(&[
+            Transition("Hang_07", "Cog_Dancers"),
+            Transition("Song_25", "Cog_Dancers"),
+        ]),
+        Split::CogworkDancersEncountered => Bool("encountered_cogwork_dancers"),
+        Split::CogworkDancers => Bool("defeated_cogwork_dancers"),
+        Split::EnterCogworkCore => Cases(&[
+            Transition("Cog_Dancers", "Cog_04"),
+            Transition("Cog_Dancers", "Cog_08"),
+            Transition("Cog_05", "Cog_04"),
+            Transition("Cog_06", "Cog_04"),
+            Transition("Cog_07", "Cog_04"),
+        ]),
+        Split::SecondSentinelAwoken => Bool("woke_song_chevalier"),
+        Split::EnterWhisperingVaults => Cases(&[
+            Transition("Library_02", "Library_01"),
+            Transition("Song_Enclave", "Library_04"),
+        ]),
+        Split::WhisperingVaultsArena => Bool("completed_library_entry_battle"),
+        Split::EnterGrandGate => Cases(&[
+            Transition("Coral_Judge_Arena", "Coral_10"),
+            Transition("Song_01c", "Song_19_entrance"),
+        ]),
+        Split::EnterChoralChambers => Cases(&[
+            Transition("Slab_01", "Song_04"),
+            Transition("Song_01c", "Song_01"),
+            Transition("Bellway_City", "Song_20"),
+            Transition("Library_13", "Song_20"),
+            Transition("Library_03", "Song_20"),
+            Transition("Arborium_01", "Song_25"),
+            Transition("Song_Enclave", "Song_25"),
+            Transition("Ward_01", "Song_05"),
+            Transition("Cog_Dancers", "Hang_07"),
+            Transition("Cog_Dancers", "Song_25"),
+            Transition("Hang_01", "Song_17"),
+            Transition("Hang_06", "Hang_07"),
+            Transition("Cog_10_Destroyed", "Song_25"),
+            Transition("Under_07b", "Song_01"),
+            GateTransition("Library_02", "Song_20b", "right2"),
+        ]),
+        Split::EnterSongclave => Cases(&[
+            Transition("Song_Enclave_Tube", "Song_Enclave"),
+            Transition("Song_25", "Song_Enclave"),
+            Transition("Library_02", "Song_Enclave"),
+            Transition("Library_04", "Song_Enclave"),
+        ]),
+        Split::JubilanaSimpleKey => Bool("merchant_enclave_simple_key"),
+        Split::TrobbioEncountered => Bool("encountered_trobbio"),
+        Split::Trobbio => Bool("defeated_trobbio"),
+        Split::TrobbioTrans => BoolTrans("defeated_trobbio"),
+        Split::TormentedTrobbioEncountered => Bool("encountered_tormented_trobbio"),
+        Split::TormentedTrobbio => Bool("defeated_tormented_trobbio"),
+        Split::TormentedTrobbioTrans => BoolTrans("defeated_tormented_trobbio"),
+        Split::MetMergwin => Bool("met_gourmand_servant"),
+        Split::DeliveredCouriersRasher => Bool("gourmand_given_meat"),
+        Split::GreatTasteReward => Bool("got_gourmand_reward"),
+        Split::Clawline => Bool("has_harpoon_dash"),
+        Split::PostClawlineArenaTrans => GateTransition("Under_18", "Under_17", "bot2"),
+        Split::EnterHighHalls => Cases(&[
+            Transition("Hang_01", "Hang_02"),
+            Transition("Hang_07", "Hang_06"),
+        ]),
+        Split::EnterHighHallsArena => Transition("Hang_06", "Hang_04"),
+        Split::HighHallsArena => Bool("hang04_battle"),
+        Split::EnterSecondSentinel => Transition("Hang_08", "Hang_17b"),
+        Split::SecondSentinelBossEncountered => Bool("encountered_song_chevalier_boss"),
+        Split::SecondSentinel => Bool("defeated_song_chevalier_boss"),
+        Split::EnterWhiteward => Transition("Song_05", "Ward_01"),
+        Split::PostWhitewardElevatorTrans => Cases(&[
+            Transition("Ward_01", "Ward_02"),
+            Transition("Ward_01", "Ward_02b"),
+            Transition("Ward_01", "Ward_05"),
+            Transition("Ward_01", "Ward_04"),
+            Transition("Ward_01", "Ward_03"),
+        ]),
+        Split::CollectedWhitewardKey => Bool("collected_ward_key"),
+        Split::CollectedSurgeonsKey => Bool("collected_ward_boss_key"),
+        Split::TheUnravelledEncountered => Bool("ward_boss_encountered"),
+        Split::TheUnravelled => Bool("ward_boss_defeated"),
+        Split::EnterMemorium => Transition("Song_25", "Arborium_01"),
+        Split::EnterPutrifiedDucts => Cases(&[
+            Transition("Arborium_11", "Aqueduct_01"),
+            Transition("Aqueduct_04", "Aqueduct_02"),
+        ]),
+        Split::FleaFestivalBegin => Bool("flea_games_started"),
+        Split::FleaFestivalEnd => Bool("flea_games_ended"),
+        Split::EnterLace2 => Transition("Cog_Dancers", "Song_Tower_01"),
+        Split::Lace2 => Bool("defeated_lace_tower"),
+        Split::PostLace2ArenaTrans => Transition("Song_Tower_01", "Tube_Hub"),
+        Split::EnterTheCradle => Transition("Tube_Hub", "Cradle_01"),
+        Split::PaleNails => Bool("has_silk_boss_needle"),
+        Split::PaleNailsTrans => BoolTrans("has_silk_boss_needle"),
+        Split::VaultkeepersMelody => Bool("has_melody_librarian"),
+        Split::VaultkeepersMelodyTrans => BoolTrans("has_melody_librarian"),
+        Split::ArchitectsMelody => Bool("has_melody_architect"),
+        Split::ArchitectsMelodyTrans => BoolTrans("has_melody_architect"),
+        Split::ConductorsMelody => Bool("has_melody_conductor"),
+        Split::ConductorsMelodyTrans => BoolTrans("has_melody_conductor"),
+        Split::UnlockedMelodyLift => Bool("unlocked_melody_lift"),
+        Split::NeedleUpgrade1 => Int("nail_upgrades", 0, 1),
+        Split::NeedleUpgrade2 => Int("nail_upgrades", 1, 2),
+        Split::NeedleUpgrade3 => Int("nail_upgrades", 2, 3),
+        Split::NeedleUpgrade4 => Int("nail_upgrades", 3, 4),
+        Split::MaskShardsTotal => Custom(mask_shards_total),
+        Split::OnObtainMaskShard => {
+            Cases(&[Int("heart_pieces", 0, 1), Int("max_health_base", 5, 6)])
+        }
+        Split::AnyMaskShard => Int("heart_pieces", 1, 2),
+        Split::MaskShard1 => Ints(&[("max_health_base", 5, 5), ("heart_pieces", 0, 1)]),
+        Split::MaskShard2 => Ints(&[("max_health_base", 5, 5), ("heart_pieces", 1, 2)]),
+        Split::MaskShard3 => Ints(&[("max_health_base", 5, 5), ("heart_pieces", 2, 3)]),
+        Split::Mask1 => Ints(&[("max_health_base", 5, 6), ("heart_pieces", 3, 0)]),
+        Split::MaskShard5 => Ints(&[("max_health_base", 6, 6), ("heart_pieces", 0, 1)]),
+        Split::MaskShard6 => Ints(&[("max_health_base", 6, 6), ("heart_pieces", 1, 2)]),
+        Split::MaskShard7 => Ints(&[("max_health_base", 6, 6), ("heart_pieces", 2, 3)]),
+        Split::Mask2 => Ints(&[("max_health_base", 6, 7), ("heart_pieces", 3, 0)]),
+        Split::MaskShard9 => Ints(&[("max_health_base", 7, 7), ("heart_pieces", 0, 1)]),
+        Split::MaskShard10 => Ints(&[("max_health_base", 7, 7), ("heart_pieces", 1, 2)]),
+        Split::MaskShard11 => Ints(&[("max_health_base", 7, 7), ("heart_pieces", 2, 3)]),
+        Split::Mask3 => Ints(&[("max_health_base", 7, 8), ("heart_pieces", 3, 0)]),
+        Split::MaskShard13 => Ints(&[("max_health_base", 8, 8), ("heart_pieces", 0, 1)]),
+        Split::MaskShard14 => Ints(&[("max_health_base", 8, 8), ("heart_pieces", 1, 2)]),
+        Split::MaskShard15 => Ints(&[("max_health_base", 8, 8), ("heart_pieces", 2, 3)]),
+        Split::Mask4 => Ints(&[("max_health_base", 8, 9), ("heart_pieces", 3, 0)]),
+        Split::MaskShard17 => Ints(&[("max_health_base", 9, 9), ("heart_pieces", 0, 1)]),
+        Split::MaskShard18 => Ints(&[("max_health_base", 9, 9), ("heart_pieces", 1, 2)]),
+        Split::MaskShard19 => Ints(&[("max_health_base", 9, 9), ("heart_pieces", 2, 3)]),
+        Split::Mask5 => Ints(&[("max_health_base", 9, 10), ("heart_pieces", 3, 0)]),
+        Split::SpoolFragmentsTotal => Custom(spool_fragments_total),
+        Split::OnObtainSpoolFragment => {
+            Cases(&[Int("silk_spool_parts", 0, 1), Int("silk_max", 9, 10)])
+        }
+        Split::AnySpoolFragment => Int("silk_spool_parts", 0, 1),
+        Split::SpoolFragment1 => Ints(&[("silk_max", 9, 9), ("silk_spool_parts", 0, 1)]),
+        Split::Spool1 => Ints(&[("silk_max", 9, 10), ("silk_spool_parts", 1, 0)]),
+        Split::SpoolFragment3 => Ints(&[("silk_max", 10, 10), ("silk_spool_parts", 0, 1)]),
+        Split::Spool2 => Ints(&[("silk_max", 10, 11), ("silk_spool_parts", 1, 0)]),
+        Split::SpoolFragment5 => Ints(&[("silk_max", 11, 11), ("silk_spool_parts", 0, 1)]),
+        Split::Spool3 => Ints(&[("silk_max", 11, 12), ("silk_spool_parts", 1, 0)]),
+        Split::SpoolFragment7 => Ints(&[("silk_max", 12, 12), ("silk_spool_parts", 0, 1)]),
+        Split::Spool4 => Ints(&[("silk_max", 12, 13), ("silk_spool_parts", 1, 0)]),
+        Split::SpoolFragment9 => Ints(&[("silk_max", 13, 13), ("silk_spool_parts", 0, 1)]),
+        Split::Spool5 => Ints(&[("silk_max", 13, 14), ("silk_spool_parts", 1, 0)]),
+        Split::SpoolFragment11 => Ints(&[("silk_max", 14, 14), ("silk_spool_parts", 0, 1)]),
+        Split::Spool6 => Ints(&[("silk_max", 14, 15), ("silk_spool_parts", 1, 0)]),
+        Split::SpoolFragment13 => Ints(&[("silk_max", 15, 15), ("silk_spool_parts", 0, 1)]),
+        Split::Spool7 => Ints(&[("silk_max", 15, 16), ("silk_spool_parts", 1, 0)]),
+        Split::SpoolFragment15 => Ints(&[("silk_max", 16, 16), ("silk_spool_parts", 0, 1)]),
+        Split::Spool8 => Ints(&[("silk_max", 16, 17), ("silk_spool_parts", 1, 0)]),
+        Split::SpoolFragment17 => Ints(&[("silk_max", 17, 17), ("silk_spool_parts", 0, 1)]),
+        Split::Spool9 => Ints(&[("silk_max", 17, 18), ("silk_spool_parts", 1, 0)]),
+        Split::ToolPouch1 => Int("tool_pouch_upgrades", 0, 1),
+        Split::ToolPouch2 => Int("tool_pouch_upgrades", 1, 2),
+        Split::ToolPouch3 => Int("tool_pouch_upgrades", 2, 3),
+        Split::ToolPouch4 => Int("tool_pouch_upgrades", 3, 4),
+        Split::CraftingKit1 => Int("tool_kit_upgrades", 0, 1),
+        Split::CraftingKit2 => Int("tool_kit_upgrades", 1, 2),
+        Split::CraftingKit3 => Int("tool_kit_upgrades", 2, 3),
+        Split::CraftingKit4 => Int("tool_kit_upgrades", 3, 4),
+        Split::AnyCrest => Bool("completed_memory_shaman"),
+        Split::ReaperCrest => Bool("completed_memory_reaper"),
+        Split::ReaperCrestTrans => BoolTrans("completed_memory_reaper"),
+        Split::WandererCrest => Bool("completed_memory_wanderer"),
+        Split::WandererCrestTrans => BoolTrans("completed_memory_wanderer"),
+        Split::BeastCrest => Bool("completed_memory_beast"),
+        Split::BeastCrestTrans => BoolTrans("completed_memory_beast"),
+        Split::ArchitectCrest => Bool("completed_memory_toolmaster"),
+        Split::ArchitectCrestTrans => BoolTrans("completed_memory_toolmaster"),
+        Split::CurseCrest => Bool("completed_memory_witch"),
+        Split::GainedCurse => Bool("gained_curse"),
+        Split::WitchCrest => Bool("belltown_doctor_cured_curse"),
+        Split::WitchCrestTrans => BoolTrans("belltown_doctor_cured_curse"),
+        Split::ShamanCrest => Bool("completed_memory_shaman"),
+        Split::ShamanCrestTrans => BoolTrans("completed_memory_shaman"),
+        Split::HunterCrestEvo1 => Str("current_crest_id", "Hunter", "Hunter_v2"),
+        Split::VesticrestYellowSlot => Bool("unlocked_extra_yellow_slot"),
+        Split::VesticrestBlueSlot => Bool("unlocked_extra_blue_slot"),
+        Split::HunterCrestEvo2 => Str("current_crest_id", "Hunter_v2", "Hunter_v3"),
+        Split::Sylphsong => Bool("has_bound_crest_upgrader"),
+        Split::SylphsongTrans => BoolTrans("has_bound_crest_upgrader"),
+        Split::AnyFlea => Bool("savedflea_coral_24"),
+        Split::FleasRescued => Custom(fleas_rescued),
+        Split::SavedFleaHuntersMarch => Bool("savedflea_ant_03"),
+        Split::SavedFleaBellhart => Bool("savedflea_belltown_04"),
+        Split::SavedFleaMarrow => Bool("savedflea_bone_06"),
+        Split::SavedFleaDeepDocksSprint => Bool("savedflea_bone_east_05"),
+        Split::SavedFleaFarFieldsPilgrimsRest => Bool("savedflea_bone_east_10_church"),
+        Split::SavedFleaFarFieldsTrap => Bool("savedflea_bone_east_17b"),
+        Split::SavedFleaSandsOfKarak => Bool("savedflea_coral_24"),
+        Split::SavedFleaBlastedSteps => Bool("savedflea_coral_35"),
+        Split::SavedFleaWormways => Bool("savedflea_crawl_06"),
+        Split::SavedFleaDeepDocksArena => Bool("savedflea_dock_03d"),
+        Split::SavedFleaDeepDocksBellway => Bool("savedflea_dock_16"),
+        Split::SavedFleaBilewaterOrgan => Bool("savedflea_dust_09"),
+        Split::SavedFleaSinnersRoad => Bool("savedflea_dust_12"),
+        Split::SavedFleaGreymoorRoof => Bool("savedflea_greymoor_06"),
+        Split::SavedFleaGreymoorLake => Bool("savedflea_greymoor_15b"),
+        Split::SavedFleaWhisperingVaults => Bool("savedflea_library_01"),
+        Split::SavedFleaSongclave => Bool("savedflea_library_09"),
+        Split::SavedFleaMountFay => Bool("savedflea_peak_05c"),
+        Split::SavedFleaBilewaterTrap => Bool("savedflea_shadow_10"),
+        Split::SavedFleaBilewaterThieves => Bool("savedflea_shadow_28"),
+        Split::SavedFleaShellwood => Bool("savedflea_shellwood_03"),
+        Split::SavedFleaSlabBellway => Bool("savedflea_slab_06"),
+        Split::SavedFleaSlabCage => Bool("savedflea_slab_cell"),
+        Split::SavedFleaChoralChambersWind => Bool("savedflea_song_11"),
+        Split::SavedFleaChoralChambersCage => Bool("savedflea_song_14"),
+        Split::SavedFleaUnderworksCauldron => Bool("savedflea_under_21"),
+        Split::SavedFleaUnderworksWispThicket => Bool("savedflea_under_23"),
+        Split::SavedFleaGiantFlea => Bool("tamed_giant_flea"),
+        Split::SavedFleaVog => Bool("met_troupe_hunter_wild"),
+        Split::SavedFleaKratt => Bool("caravan_lech_saved"),
+        Split::AnyBellway => Bool("unlocked_peak_station"),
+        Split::PutrifiedDuctsStation => Bool("unlocked_aqueduct_station"),
+        Split::BellhartStation => Bool("unlocked_belltown_station"),
+        Split::FarFieldsStation => Bool("unlocked_boneforest_east_station"),
+        Split::GrandBellwayStation => Bool("unlocked_city_station"),
+        Split::BlastedStepsStation => Bool("unlocked_coral_tower_station"),
+        Split::DeepDocksStation => Bool("unlocked_docks_station"),
+        Split::GreymoorStation => Bool("unlocked_greymoor_station"),
+        Split::SlabStation => Bool("unlocked_peak_station"),
+        Split::BilewaterStation => Bool("unlocked_shadow_station"),
+        Split::ShellwoodStation => Bool("unlocked_shellwood_station"),
+        Split::BellwayTrans => Transition("Cinematic_Stag_travel", "Bellway_01"),
+        Split::ChoralChambersTube => Bool("unlocked_song_tube"),
+        Split::UnderworksTube => Bool("unlocked_under_tube"),
+        Split::GrandBellwayTube => Bool("unlocked_city_bellway_tube"),
+        Split::HighHallsTube => Bool("unlocked_hang_tube"),
+        Split::SongclaveTube => Bool("unlocked_enclave_tube"),
+        Split::MemoriumTube => Bool("unlocked_arborium_tube"),
+        Split::VentricaTrans => GateTransition("Song_Enclave_Tube", "Tube_Hub", "door_tubeEnter"),
+        Split::SeenShakraBonebottom => Bool("seen_mapper_bonetown"),
+        Split::SeenShakraMarrow => Bool("seen_mapper_bone_forest"),
+        Split::SeenShakraDeepDocks => Bool("seen_mapper_docks"),
+        Split::SeenShakraFarFields => Bool("seen_mapper_wilds"),
+        Split::SeenShakraWormways => Bool("seen_mapper_crawl"),
+        Split::SeenShakraGreymoor => Bool("seen_mapper_greymoor"),
+        Split::SeenShakraBellhart => Bool("seen_mapper_bellhart"),
+        Split::SeenShakraShellwood => Bool("seen_mapper_shellwood"),
+        Split::SeenShakraHuntersMarch => Bool("seen_mapper_hunters_nest"),
+        Split::SeenShakraBlastedSteps => Bool("seen_mapper_judge_steps"),
+        Split::SeenShakraSinnersRoad => Bool("seen_mapper_dustpens"),
+        Split::SeenShakraMountFay => Bool("seen_mapper_peak"),
+        Split::SeenShakraBilewater => Bool("seen_mapper_shadow"),
+        Split::SeenShakraSandsOfKarak => Bool("seen_mapper_coral_caverns"),
+        Split::ShakraMapBuyout => AllBools(&[
+            "has_moss_grotto_map",
+            "has_boneforest_map",
+            "has_docks_map",
+            "has_wilds_map",
+            "has_crawl_map",
+            "has_hunters_nest_map",
+            "has_greymoor_map",
+            "has_bellhart_map",
+            "has_shellwood_map",
+            "has_judge_steps_map",
+            "has_dustpens_map",
+            "has_peak_map",
+            "has_coral_map",
+            "has_swamp_map",
+        ]),
+        Split::MetJubilanaEnclave => Bool("met_city_merchant_enclave"),
+        Split::MetShermaEnclave => Bool("met_sherma_enclave"),
+        Split::UnlockedPrinceCage => Bool("unlocked_dust_cage"),
+        Split::GreenPrinceInVerdania => Int("green_prince_location", 2, 3),
+        Split::SeenFleatopiaEmpty => Bool("seen_fleatopia_empty"),
+        Split::EnterBellEater => Transition("Bellway_08", "Bellway_Centipede_Arena"),
+        Split::BeastlingCall => Bool("has_fast_travel_teleport"),
+        Split::SilkSoar => Bool("has_super_jump"),
+        Split::SilkSoarTrans => BoolTrans("has_super_jump"),
+        Split::ElegyOfTheDeep => Bool("has_needolin_memory_powerup"),
+        Split::EnterNylethMemory => Transition("Shellwood_11b", "Shellwood_11b_Memory"),
+        Split::NylethEncountered => Bool("encountered_flower_queen"),
+        Split::Nyleth => Bool("defeated_flower_queen"),
+        Split::HeartNyleth => Bool("collected_heart_flower"),
+        Split::EnterKhannMemory => Transition("Coral_Tower_01", "Memory_Coral_Tower"),
+        Split::KhannEncountered => Bool("encountered_coral_king"),
+        Split::CrustKingKhann => Bool("defeated_coral_king"),
+        Split::HeartKhann => Bool("collected_heart_coral"),
+        Split::EnterKarmelitaMemory => Transition("Ant_Queen", "Memory_Ant_Queen"),
+        Split::SkarrsingerKarmelita => Bool("defeated_ant_queen"),
+        Split::HeartKarmelita => Bool("collected_heart_hunter"),
+        Split::EnterVerdaniaMemory => Transition("Clover_01", "Clover_01b"),
+        Split::EnterVerdaniaCastle => Transition("Clover_04b", "Clover_10"),
+        Split::Palestag => Bool("defeated_white_cloverstag"),
+        Split::PalestagTrans => BoolTrans("defeated_white_cloverstag"),
+        Split::CloverDancersEncountered => Bool("encountered_clover_dancers"),
+        Split::CloverDancers => Bool("defeated_clover_dancers"),
+        Split::HeartClover => Bool("collected_heart_clover"),
+        Split::EnterRedMemory => Transition("Tut_04", "Memory_Red"),
+        Split::RedMemory => Bool("completed_red_memory"),
+        Split::BellhouseKeyConversation => Bool("belltown_greeter_house_full_dlg"),
+        Split::VerdaniaLakeFountainOrbs => Bool("summoned_lake_orbs"),
+        Split::VerdaniaOrbsCollected => Custom(verdania_orbs),
+        Split::Forebrothers => Bool("defeated_dock_foremen"),
+        Split::ForebrothersTrans => BoolTrans("defeated_dock_foremen"),
+        Split::Groal => Bool("defeated_swamp_shaman"),
+        Split::SavageBeastfly1 => Bool("defeated_bone_flyer_giant"),
+        Split::SavageBeastfly2 => Bool("defeated_bone_flyer_giant_golem_scene"),
+        Split::CaravanTroupeGreymoor => Int("caravan_troupe_location", 0, 1),
+        Split::CaravanTroupeBlastedSteps => Int("caravan_troupe_location", 1, 2),
+        Split::CaravanTroupeFleatopia => Int("caravan_troupe_location", 2, 3),
+        Split::RelicsCollected => Custom(relics_collected),
+        Split::SoldRelic => Bool("belltown_relic_dealer_gave_relic"),
+        Split::PavoTimePassed => Bool("belltown_greeter_met_time_passed"),
+        Split::SongclaveBell => Bool("bell_shrine_enclave"),
+        Split::SkullTyrant1 => Bool("skull_king_defeated"),
+        Split::ShermaReturned => Bool("sherma_healer_active"),
+        Split::JubilanaRescuedMemorium => Bool("enclave_merchant_saved"),
+        Split::JubilanaRescuedChoralChambers => Bool("city_merchant_saved"),
+        Split::SilkAndSoulOffered => Bool("caretaker_offered_snare_quest"),
+        Split::SoulSnareReady => Bool("soul_snare_ready"),
+        Split::EnterDestroyedCogworks => Cases(&[
+            Transition("Song_Tower_Destroyed", "Cog_09_Destroyed"),
+            Transition("Song_25", "Cog_10_Destroyed"),
+        ]),
+        Split::DestroyedCogworksVoidArena => Bool("completed_cog_10_abyss_battle"),
+        Split::EnterSeth => Transition("Under_27", "Shellwood_22"),
+        Split::Seth => Bool("defeated_seth"),
+        Split::BallowMoved => Bool("ballow_moved_to_diving_bell"),
+        Split::Act3Started => Bool("black_thread_world"),
+        Split::DivingBellAbyssTrans => Transition("Room_Diving_Bell", "Room_Diving_Bell_Abyss"),
+        Split::EnterAbyss => Cases(&[
+            Transition("Abyss_03", "Abyss_02"),
+            Transition("Abyss_11", "Abyss_02b"),
+        ]),
+        Split::AbyssEscape => Bool("completed_abyss_ascent"),
+        Split::ReverseAbyssEscapeTrans => Transition("Abyss_09", "Abyss_13"),
+        Split::LastDiveTrans => Transition("Abyss_05", "Last_Dive"),
+        Split::LostLaceEncountered => Bool("encountered_lost_lace"),
+        Split::AnyTool => Tool("Tri Pin"),
+        Split::ToolAcquired => Custom(tool_acquired),
+        Split::AscendantsGrip => Tool("Wallcling"),
+        Split::BarbedBracelet => Tool("Barbed Wire"),
+        Split::ClawMirror => Tool("Dazzle Bind"),
+        Split::ClawMirrors => Tool("Dazzle Bind Upgraded"),
+        Split::Cogfly => Tool("Cogwork Flier"),
+        Split::CogworkWheel => Tool("Cogwork Saw"),
+        Split::Compass => Tool("Compass"),
+        Split::Conchcutter => Tool("Conch Drill"),
+        Split::Curveclaw => Tool("Curve Claws"),
+        Split::Curvesickle => Tool("Curve Claws Upgraded"),
+        Split::DeadBugsPurse => Tool("Dead Mans Purse"),
+        Split::DelversDrill => Tool("Screw Attack"),
+        Split::DruidsEye => Tool("Mosscreep Tool 1"),
+        Split::DruidsEyes => Tool("Mosscreep Tool 2"),
+        Split::EggofFlealia => Tool("Flea Charm"),
+        Split::FleaBrew => Tool("Flea Brew"),
+        Split::Flintslate => Tool("Flintstone"),
+        Split::FracturedMask => Tool("Fractured Mask"),
+        Split::InjectorBand => Tool("Quickbind"),
+        Split::Longclaw => Tool("Longneedle"),
+        Split::Longpin => Tool("Harpoon"),
+        Split::MagmaBell => Tool("Lava Charm"),
+        Split::MagnetiteBrooch => Tool("Rosary Magnet"),
+        Split::MagnetiteDice => Tool("Magnetite Dice"),
+        Split::MemoryCrystal => Tool("Revenge Crystal"),
+        Split::Multibinder => Tool("Multibind"),
+        Split::NeedlePhial => Tool("Extractor"),
+        Split::Pimpillo => Tool("Pimpilo"),
+        Split::PinBadge => Tool("Pinstress Tool"),
+        Split::PlasmiumPhial => Tool("Lifeblood Syringe"),
+        Split::PollipPouch => Tool("Poison Pouch"),
+        Split::QuickSling => Tool("Quick Sling"),
+        Split::ReserveBind => Tool("Reserve Bind"),
+        Split::RosaryCannon => Tool("Rosary Cannon"),
+        Split::SawtoothCirclet => Tool("Brolly Spike"),
+        Split::Scuttlebrace => Tool("Scuttlebrace"),
+        Split::ShardPendant => Tool("Bone Necklace"),
+        Split::ShellSatchel => Tool("Shell Satchel"),
+        Split::SilkshotArchitect => Tool("WebShot Architect"),
+        Split::SilkshotForge => Tool("WebShot Forge"),
+        Split::SilkshotWeaver => Tool("WebShot Weaver"),
+        Split::SilkspeedAnklets => Tool("Sprintmaster"),
+        Split::SnareSetter => Tool("Silk Snare"),
+        Split::SnitchPick => Tool("Thief Claw"),
+        Split::SpiderStrings => Tool("Musician Charm"),
+        Split::SpoolExtender => Tool("Spool Extender"),
+        Split::StingShard => Tool("Sting Shard"),
+        Split::StraightPin => Tool("Straight Pin"),
+        Split::Tacks => Tool("Tack"),
+        Split::ThiefsMark => Tool("Thief Charm"),
+        Split::ThreefoldPin => Tool("Tri Pin"),
+        Split::ThrowingRing => Tool("Shakra Ring"),
+        Split::VoltFilament => Tool("Zap Imbuement"),
+        Split::Voltvessels => Tool("Lightning Rod"),
+        Split::WardingBell => Tool("Bell Bind"),
+        Split::Weavelight => Tool("White Ring"),
+        Split::WeightedBelt => Tool("Weighted Anklet"),
+        Split::WispfireLantern => Tool("Wisp Lantern"),
+        Split::WreathofPurity => Tool("Maggot Charm"),
+    }
+}